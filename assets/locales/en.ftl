@@ -0,0 +1,642 @@
+# English message catalog
+# Keys use lowercase letters and hyphens; arguments are written as { $name }
+
+## Language
+language-auto = System default
+
+## Upload
+files-staged = Files ready to upload: { $count }
+no-file-selected = No file selected.
+select-file-first = Please select a file first.
+nothing-to-upload-in = Nothing to upload in { $path }
+dropped-files-rejected = { $count } dropped { $count ->
+        [one] file was
+       *[other] files were
+    } not uploaded: { $error }
+dropped-files-partly-rejected = { $status }; { $count } dropped { $count ->
+        [one] file was
+       *[other] files were
+    } not uploaded: { $error }
+upload-succeeded = Uploaded, ID: { $id }
+upload-failed = Upload failed: { $error }
+upload-queue-remaining = { $status }, { $count } more { $count ->
+        [one] file
+       *[other] files
+    } in the queue
+upload-queued = Added to the upload queue, files waiting: { $count }
+uploading = Uploading { $name }...
+
+## Download
+downloading-to = Downloading { $name } to { $path }...
+downloading-id-to = Downloading file (ID: { $id }) to { $path }...
+no-download-path = No download location selected.
+enter-download-id = Please enter the ID of the file to download.
+download-failed = Download failed: { $error }
+download-conflict = File already exists: { $path }, choose how to proceed
+batch-download-nothing-selected = No files selected for batch download.
+batch-download-started = Downloading { $count } { $count ->
+        [one] file
+       *[other] files
+    }...
+no-batch-download-path = No batch download location selected.
+
+## File list
+file-id-not-found = File ID not found: { $id }
+id-already-listed = The file list already contains ID: { $id }
+probing-blob = Checking whether blob { $id } is available...
+blob-added-with-size = Added { $name } ({ $size } bytes)
+blob-added = Added { $name }
+add-blob-failed = Failed to add: { $error }
+file-deleted = Deleted the blob from Walrus and removed the entry, ID: { $id }
+keystore-loaded = Read { $count } { $count ->
+    [one] address
+   *[other] addresses
+} from the keystore
+delete-failed = Failed to delete from Walrus: { $error }
+deleting-from-walrus = Deleting { $name } from Walrus...
+id-copied = File ID copied to clipboard: { $id }
+batch-delete-nothing-selected = No files selected for batch delete.
+nothing-selected = No files selected.
+
+## Trash
+moved-to-trash = Moved { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } to the trash; they can be restored from there.
+restored-from-trash = Restored { $name } from the trash
+trash-purged = Permanently deleted { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } from the trash.
+trash-empty = The trash is empty.
+trash-entry-not-found = ID not found in the trash: { $id }
+
+## Preview and thumbnails
+encrypted-preview-too-large = The encrypted file is too large to preview
+image-too-large = Image is too large
+
+## Sharing
+share-link-copied = Share link copied to clipboard: { $url }
+share-link-failed = Could not build a share link: { $error }
+select-files-to-share = Please select the files to share first.
+publishing-bundle = Publishing a bundle with { $count } { $count ->
+        [one] file
+       *[other] files
+    }...
+bundle-published = Bundle published and copied to clipboard, manifest ID: { $id }
+publish-bundle-failed = Failed to publish bundle: { $error }
+loading-bundle = Loading bundle { $id }...
+bundle-loaded = The bundle contains { $count } { $count ->
+        [one] file
+       *[other] files
+    }
+open-bundle-failed = Failed to open bundle: { $error }
+import-source-bundle = bundle { $id }
+bundle-download-started = Downloading { $count } { $count ->
+        [one] file
+       *[other] files
+    } from the bundle...
+
+## Identity keys
+public-key-copied = Public key copied to clipboard
+contact-added = Added contact { $name }
+
+## Config import and export
+config-exported = Config exported to: { $path }
+config-exported-without-keys = Config exported to: { $path }. The file keys of { $count } encrypted { $count ->
+        [one] entry was
+       *[other] entries were
+    } left out; share those files through a share bundle.
+export-config-failed = Failed to export config: { $error }
+serialize-files-failed = Failed to serialize the file list: { $error }
+no-export-path = No export location selected.
+parse-import-failed = Failed to parse the import file: { $error }
+read-import-failed = Failed to read the import file: { $error }
+no-import-file = No import file selected.
+config-passphrase-required-for-upload = Enter a config passphrase first; remote configs are encrypted before upload.
+uploading-config = Encrypting and uploading config...
+upload-config-failed = Config upload failed: { $error }
+config-uploaded = Config uploaded, ID { $id } copied to clipboard
+enter-config-id = Please enter the config ID to load.
+loading-config = Loading config from ID: { $id }...
+config-is-encrypted = This config is encrypted, please enter the config passphrase.
+load-config-failed = Failed to load config: { $error }
+enter-config-passphrase = Please enter the config passphrase.
+decrypting-config = Decrypting config...
+decrypt-config-failed = Failed to decrypt config: { $error }
+config-load-cancelled = Config loading cancelled.
+config-imported = Imported config from { $source } ({ $mode }), { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } in total.
+import-cancelled = Import cancelled.
+import-undone = The last import was undone.
+nothing-to-undo = There is no import to undo.
+import-source-config = config ID: { $id }
+import-staged = Read { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } from { $source }, please confirm how to import.
+
+## Config history
+loading-snapshot = Loading config snapshot { $id }...
+comparing-snapshot = Comparing config snapshot { $id }.
+import-source-snapshot = config snapshot: { $id }
+load-snapshot-failed = Failed to load config snapshot: { $error }
+
+## Sync
+sync-enabled = Auto sync enabled, every { $minutes } { $minutes ->
+        [one] minute
+       *[other] minutes
+    }.
+sync-disabled = Auto sync disabled.
+sync-pointer-set = Sync pointer file: { $path }
+no-sync-pointer-selected = No sync pointer file selected.
+sync-completed = Sync complete.
+sync-completed-with-conflicts = Sync complete with { $count } { $count ->
+        [one] conflict
+       *[other] conflicts
+    }, see the sync panel for details.
+sync-failed = Sync failed: { $error }
+sync-pointer-required = Please set a sync pointer file first.
+sync-passphrase-required = Sync needs the config passphrase, please enter it first.
+syncing = Syncing...
+
+## Workspaces
+sync-busy-switching-workspace = Sync in progress, switch workspaces once it finishes.
+transfer-busy-switching-workspace = Uploads or blob lookups are still running, switch workspaces once they finish.
+workspace-switched = Switched to workspace: { $name }
+workspace-created = Created workspace: { $name }
+create-workspace-failed = Failed to create workspace: { $error }
+cannot-delete-active-workspace = The active workspace cannot be deleted.
+workspace-deleted = Deleted workspace: { $name }
+network-profile-saved = Network profile for workspace { $name } saved.
+invalid-network-profile = Invalid network profile: { $error }
+select-target-workspace = Please select a target workspace first.
+target-is-current-workspace = The target workspace is the current workspace.
+entries-copied = Copied { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } to workspace { $target } ({ $added } new).
+entries-moved = Moved { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } to workspace { $target } ({ $added } new).
+
+## UI: title bar
+app-title = Walrus Drive
+window-title = Walrus Drive - { $workspace }
+import-config = Import config
+export-config = Export config
+upload-config = Upload config
+config-history = Config history
+sync = Sync
+syncing-short = Syncing...
+undo-import = Undo import
+workspaces = Workspaces
+identity-keys = Identity keys
+open-command-palette = Commands (Ctrl+K)
+trash-button = Trash ({ $count })
+
+## UI: upload area
+choose-files = Choose files
+no-files-chosen = No files chosen (multiple allowed)
+files-chosen = { $count } { $count ->
+        [one] file
+       *[other] files
+    } chosen
+upload-all = Upload all ({ $count })
+clear = Clear
+wrap-metadata = Include name and type (restored when downloading by ID)
+encrypt-uploads = Encrypt uploads
+deletable-uploads = Store as deletable
+upload-progress = Upload progress: { $percent }%
+upload-progress-queued = Upload progress: { $percent }%  Waiting: { $count } { $count ->
+        [one] file
+       *[other] files
+    }
+drop-hint = You can also drag files or folders onto the window to upload them
+price-unknown = Storage price not queried yet
+price-loading = Querying the storage price…
+price-ready = Cost estimates will appear once files are chosen
+price-unavailable = Could not get the storage price: { $error }
+cost-estimate-total = Estimated encoded size { $encoded }, about { $cost }{ $skipped ->
+    [0] {""}
+    [one] {" "}(1 file without a valid size or epochs is not counted)
+   *[other] {" "}({ $skipped } files without a valid size or epochs are not counted)
+}
+refresh-price = Refresh price
+workspace-spend = Spent in this workspace: { $cost } over { $count } { $count ->
+    [one] upload
+   *[other] uploads
+}
+file-name = File name
+tags = Tags, comma separated
+epochs = Epochs
+remove = Remove
+drop-release = Release to upload
+drop-details = Multiple files and folders are supported; every file in a folder is queued for upload
+
+## UI: file list
+search-files = Search files...
+list-view = List view
+grid-view = Grid view
+file-id = File ID
+uploaded-at = Uploaded at
+actions = Actions
+copy-id = Copy ID
+share = Share
+preview = Preview
+download = Download
+delete = Delete
+loading = Loading...
+file = File
+invert-selection = Invert
+selection-summary = { $count } of { $total } { $total ->
+        [one] file
+       *[other] files
+    } selected, { $size } in total
+selection-unknown-sizes = ({ $count } without a known size)
+batch-delete = Delete selected
+batch-download = Download selected
+share-selected = Share selected
+share-selected-encrypted = Share selected encrypted ({ $count } { $count ->
+        [one] recipient
+       *[other] recipients
+    })
+target-workspace = Target workspace
+copy-to-workspace = Copy to workspace
+move-to-workspace = Move to workspace
+
+## UI: download area
+enter-file-id = Enter a file ID
+download-by-id = Download by ID
+load-config = Load config
+open-bundle = Open bundle
+when-file-exists = When the file exists:
+file-name-optional = File name (optional)
+tags-optional = Tags, comma separated (optional)
+add-to-list = Add to list
+config-passphrase = Config passphrase
+remote-config-encrypted = This remote config is encrypted, please enter the config passphrase
+decrypt = Decrypt
+cancel = Cancel
+close = Close
+
+## UI: download conflicts
+download-exists-title = The downloaded file already exists
+remember-conflict-choice = Remember this choice for later downloads (also applies to the remaining conflicts)
+conflict-file = { $path } ({ $size } bytes)
+conflicts-remaining = { $count } more { $count ->
+        [one] conflict
+       *[other] conflicts
+    } waiting
+
+## UI: sharing
+bundle-summary-with-size = { $count } { $count ->
+        [one] file
+       *[other] files
+    }, { $size } in total, created at { $created_at }
+bundle-summary = { $count } { $count ->
+        [one] file
+       *[other] files
+    }, created at { $created_at }
+bundle-title = Bundle { $id }
+download-all = Download all
+import-to-list = Import to list
+qr-code-failed = Could not generate a QR code
+share-title = Share { $name }
+share-encrypted-use-bundle = This file was uploaded encrypted. A link or QR code would only download ciphertext, so share it through a share bundle with the recipient selected; the bundle carries the file key sealed for them.
+share-link = Share link
+include-name-in-link = Include the file name in the link
+copy-link = Copy link
+scan-qr-hint = Scan the QR code with a phone to download
+
+## UI: preview
+loading-preview = Loading preview...
+pdf-version = PDF document, version { $version }
+pdf-pages = { $count } { $count ->
+        [one] page
+       *[other] pages
+    }
+pdf-pages-unknown = Page count unknown
+pdf-title = Title: { $title }
+pdf-author = Author: { $author }
+pdf-preview-hint = Pages are not rendered here; download the file to read it.
+preview-failed = Preview failed: { $error }
+preview-truncated = { $name } (over the preview limit, showing the beginning only)
+blob-id-detail = Blob ID: { $id }
+blob-object-id = Blob object: { $id }
+blob-cost = Storage cost paid at upload: { $cost }
+owned-by = Owner: { $address }
+owned-by-workspace-address = Owner: { $address } (this workspace's address)
+owned-by-publisher = Owner: the publisher (no address was set when uploading)
+blob-object-unknown = Owner: unknown (the blob already existed or the record predates ownership tracking)
+
+## UI: import preview and snapshot diff
+changes-added = { $count } added
+changes-removed = { $count } removed
+changes-conflicts = { $count } { $count ->
+        [one] conflict
+       *[other] conflicts
+    }
+changes-unchanged = { $count } unchanged
+change-conflict = ! { $id }: local { $local } / { $label } { $incoming }
+keep-incoming = use imported
+keep-local = keep local
+change-source-import = imported
+change-source-snapshot = snapshot
+import-preview-title = Import preview: { $source }
+import-mode = Import mode
+confirm-import = Confirm import
+
+## UI: config history
+no-snapshots = No config snapshots have been published yet.
+none = none
+snapshot-file-count = { $count } { $count ->
+        [one] file
+       *[other] files
+    }
+previous-snapshot = Previous: { $id }
+snapshot-epochs = { $count } { $count ->
+        [one] epoch
+       *[other] epochs
+    }
+snapshot-epochs-label = Store new snapshots and share lists for (epochs)
+compare = Compare
+restore = Restore
+config-history-title = Config history ({ $count } { $count ->
+        [one] snapshot
+       *[other] snapshots
+    })
+snapshot-diff-title = Snapshot diff: { $id } ({ $created_at })
+restore-snapshot = Restore this snapshot
+
+## UI: workspace panel
+current = Current
+switch = Switch
+new-workspace-name = New workspace name
+create = Create
+network-profile-title = Network profile for workspace { $name }
+aggregator-url = Aggregator URL
+publisher-url = Publisher URL
+chain-settings-title = On-chain operations (deleting blobs uses the walrus CLI and its wallet)
+walrus-cli-path = walrus executable
+walrus-config-path = walrus client config file (optional)
+sui-address-placeholder = Sui address that receives uploaded blob objects (0x…)
+sui-address-unset = No address set: uploaded blob objects stay with the publisher and cannot be extended, deleted or transferred later.
+sui-address-set = Newly uploaded blob objects are sent to this address.
+keystore-path-placeholder = Local Sui keystore (optional, e.g. ~/.sui/sui_config/sui.keystore)
+load-keystore = Read keystore
+choose-keystore-address = Choose an address from the keystore
+save-network-profile = Save network profile
+
+## UI: identity panel
+my-public-key = My public key
+fingerprint = Fingerprint: { $value }
+copy-public-key = Copy public key
+identity-unavailable = Identity key unavailable
+contacts-title = Contacts ({ $count })
+contacts-empty = No contacts yet; add someone's public key to share encrypted bundles with them
+share-to = Share with
+contact-name = Contact name
+contact-public-key = Their public key (ws25519:...)
+add = Add
+
+## UI: command palette
+command-palette = Command palette
+command-palette-placeholder = Type a command...
+command-palette-hint = Enter runs the first match, Esc closes. Shortcuts: Ctrl+O choose files, Ctrl+F search, Ctrl+A select all, Delete remove selection, Ctrl+Shift+D download selection.
+no-matching-commands = No matching commands
+command-choose-files = Choose files to upload
+command-upload-all = Upload all
+command-focus-search = Search files
+command-select-all = Select all in the list
+command-invert-selection = Invert selection in the list
+command-delete-selection = Delete selected files
+command-download-selection = Download selected files
+command-share-selection = Share selected files
+command-toggle-grid-view = Toggle list/grid view
+command-download-by-id = Download by ID
+command-open-bundle = Open bundle
+command-import-config = Import config
+command-export-config = Export config
+command-upload-config = Upload config
+command-load-config = Load remote config
+command-undo-import = Undo import
+command-config-history = Config history
+command-sync-panel = Sync settings
+command-sync-now = Sync now
+command-workspaces = Manage workspaces
+command-identity-keys = Identity keys and contacts
+command-trash = Trash
+command-activity-log = Activity log
+
+## UI: confirmation dialog
+confirm-title = Please confirm
+confirm-delete-entries = Delete { $count } { $count ->
+        [one] entry
+       *[other] entries
+    }? They will be moved to the trash and purged after { $days } { $days ->
+        [one] day
+       *[other] days
+    }; until then they can be restored. The blobs on Walrus are not affected.
+confirm-delete-entries-kept = Delete { $count } { $count ->
+        [one] entry
+       *[other] entries
+    }? They will be kept in the trash until you remove them. The blobs on Walrus are not affected.
+confirm-delete-from-walrus = Delete this blob from Walrus? Nobody will be able to read it afterwards and the entry is removed from the list. This cannot be undone.
+confirm-replace-import = Replacing the list removes { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } from the current list (removed entries go to the trash). Continue?
+confirm-purge-trash = Permanently delete { $count } { $count ->
+        [one] entry
+       *[other] entries
+    } from the trash? This cannot be undone and the blob IDs will be lost.
+confirm-delete-workspace = Delete workspace { $name }? Its file list, settings and trash will be deleted. This cannot be undone.
+confirm-shorten-retention = Keeping trashed entries for { $days } { $days ->
+        [one] day
+       *[other] days
+    } permanently deletes { $count } { $count ->
+        [one] entry that is
+       *[other] entries that are
+    } already older than that. Continue?
+confirm-more-entries = ...and { $count } more
+move-to-trash = Move to trash
+replace-list = Replace list
+delete-permanently = Delete permanently
+delete-workspace = Delete workspace
+shorten-retention = Shorten and delete
+forget = Forget
+delete-from-walrus = Delete from Walrus
+
+## UI: trash
+trash-title = Trash ({ $count } { $count ->
+        [one] entry
+       *[other] entries
+    })
+trash-retention-days = Days to keep (0 keeps forever, press Enter to apply)
+invalid-retention-days = Not a valid number of days: { $input }
+empty-trash = Empty trash
+trash-is-empty = The trash is empty.
+deleted-at = Deleted { $time }
+days-left = { $days } { $days ->
+        [one] day
+       *[other] days
+    } left
+kept-forever = Kept forever
+
+## UI: activity log
+activity-log-button = Activity
+activity-title = Activity log (showing { $shown } of { $count })
+activity-empty = No matching activity.
+export-activity = Export CSV
+activity-all-types = All types
+activity-all-results = All results
+activity-succeeded = Succeeded
+activity-failed = Failed
+activity-upload = Upload
+activity-download = Download
+activity-delete = Delete
+activity-import = Import
+activity-config-push = Config push
+activity-sync = Sync
+activity-bundle-subject = Share bundle ({ $count } { $count ->
+        [one] file
+       *[other] files
+    })
+activity-config-subject = Config snapshot ({ $count } { $count ->
+        [one] entry
+       *[other] entries
+    })
+activity-entries-subject = { $count } { $count ->
+        [one] entry
+       *[other] entries
+    }
+activity-import-detail = { $mode }, { $count } incoming { $count ->
+        [one] entry
+       *[other] entries
+    }
+activity-exported = Exported { $count } activity { $count ->
+        [one] record
+       *[other] records
+    } to: { $path }
+export-activity-failed = Failed to export the activity log: { $error }
+
+## UI: sync panel
+last-synced = Last synced: { $at } (snapshot { $id })
+never-synced = Not synced yet
+deleted = deleted
+last-sync-conflicts = Conflicts in the last sync: { $count }
+sync-conflict = ! { $id }: local { $local } / remote { $remote } -> kept { $kept }
+sync-now = Sync now
+multi-device-sync = Multi-device sync
+auto-sync = Auto sync
+sync-pointer-placeholder = Sync pointer file path (e.g. a file in a shared folder)
+choose = Choose
+sync-interval-minutes = Sync interval (minutes)
+
+## Download conflicts and import modes
+conflict-policy-ask = Ask
+conflict-policy-overwrite = Overwrite
+conflict-policy-skip = Skip
+conflict-policy-rename = Rename automatically
+conflict-policy-compare-hash = Compare hashes, skip if identical
+download-saved = Downloaded to: { $path }
+download-skipped = File exists, skipped: { $path }
+download-identical = An identical file already exists, skipped: { $path }
+download-exists = File already exists: { $path }
+invalid-file-name = Invalid file name: { $name }
+no-free-file-name = Could not find a free file name for { $path }
+import-mode-replace = Replace
+import-mode-merge = Merge
+import-mode-merge-keep-newer = Merge (keep newer)
+
+## Errors: file content
+serialize-metadata-failed = Failed to serialize file metadata: { $error }
+envelope-corrupted = The file metadata envelope is corrupted
+parse-metadata-failed = Failed to parse file metadata: { $error }
+envelope-mismatch = The file content does not match the size or hash in its metadata
+encrypt-file-failed = Failed to encrypt the file: { $error }
+not-an-encrypted-file = The file is not encrypted
+encrypted-file-corrupted = The encrypted file is corrupted
+invalid-file-key = Invalid file key
+wrong-file-key = Wrong file key or corrupted file
+missing-file-key = The file is encrypted but its file key is missing
+hex-view-truncated = only the first { $limit } bytes are shown
+qr-code-error = Could not generate a QR code: { $error }
+save-thumbnail-failed = Failed to save the thumbnail: { $error }
+decode-image-failed = Could not decode the image: { $error }
+empty-staged-name = The file name for { $path } must not be empty
+invalid-epochs = Epochs must be a whole number from 1 to { $max }: { $input }
+
+## Errors: bundles and identity keys
+not-a-bundle = This ID is not a share bundle manifest
+bundle-version-too-new = Bundle version { $version } is too new, please update the app to open it
+serialize-bundle-failed = Failed to serialize the bundle: { $error }
+encrypted-bundle-needs-recipients = The selected files are encrypted, please choose recipients first
+sealed-bundle-needs-identity = This bundle is encrypted and needs an identity key to open
+bundle-corrupted = The bundle content is corrupted
+identity-corrupted = The identity private key is corrupted
+invalid-public-key = Invalid public key, it should start with { $prefix }
+empty-contact-name = The contact name must not be empty
+duplicate-contact = This public key is already in your contacts
+encrypt-failed = Encryption failed: { $error }
+no-recipients = At least one recipient is required
+not-a-recipient = This share was not addressed to your identity key
+recipient-corrupted = The recipient entry is corrupted
+unwrap-key-failed = Could not unwrap the content key
+decrypt-failed = Decryption failed, the content may be corrupted
+
+## Errors: config
+unsupported-kdf = Unsupported key derivation algorithm: { $algorithm }
+decode-salt-failed = Could not decode the salt: { $error }
+invalid-kdf-params = Invalid key derivation parameters: { $error }
+kdf-params-too-expensive = The config asks for unreasonably expensive key derivation (m_cost { $m_cost }, t_cost { $t_cost }, p_cost { $p_cost }) and was rejected
+derive-key-failed = Key derivation failed: { $error }
+empty-config-passphrase = The config passphrase must not be empty
+encrypt-config-failed = Failed to encrypt the config: { $error }
+serialize-encrypted-config-failed = Failed to serialize the encrypted config: { $error }
+parse-encrypted-config-failed = Failed to parse the encrypted config: { $error }
+not-an-encrypted-config = Not an encrypted config
+unsupported-config-version = Unsupported encrypted config version: { $version }
+decode-nonce-failed = Could not decode the nonce: { $error }
+invalid-nonce-length = The encrypted config has an invalid nonce length
+decode-ciphertext-failed = Could not decode the ciphertext: { $error }
+wrong-passphrase = Wrong passphrase or corrupted config
+config-not-utf8 = The config data is not valid UTF-8: { $error }
+parse-config-failed = Failed to parse the config data: { $error }
+serialize-config-failed = Failed to serialize the config: { $error }
+snapshot-is-encrypted = This snapshot is encrypted, please enter the config passphrase first
+
+## Errors: sync and workspaces
+read-sync-pointer-failed = Failed to read the sync pointer: { $error }
+parse-sync-pointer-failed = Failed to parse the sync pointer: { $error }
+serialize-sync-pointer-failed = Failed to serialize the sync pointer: { $error }
+create-pointer-dir-failed = Could not create the pointer directory: { $error }
+write-sync-pointer-failed = Failed to write the sync pointer: { $error }
+empty-workspace-name = The workspace name must not be empty
+workspace-name-too-long = The workspace name must not exceed { $max } characters
+workspace-name-starts-with-dot = The workspace name must not start with .
+workspace-name-invalid-char = The workspace name must not contain { $char }
+workspace-exists = Workspace { $name } already exists
+delete-workspace-failed = Failed to delete the workspace: { $error }
+
+## Errors: network
+blob-not-deletable = { $name } was not stored as deletable and cannot be deleted from Walrus; it can only expire or be forgotten locally.
+blob-not-owned = { $name } was not stored to the configured Sui address, so this wallet cannot delete it.
+invalid-object-id = Invalid Sui object ID: { $id }
+walrus-cli-unavailable = Could not run the walrus CLI { $binary }: { $error }
+walrus-cli-failed = The walrus command failed: { $error }
+invalid-sui-address = Invalid Sui address: { $address } (expected 0x followed by 64 hex digits)
+invalid-keystore = Not a valid Sui keystore: { $error }
+read-keystore-failed = Failed to read the keystore: { $error }
+keystore-has-no-ed25519-keys = The keystore has no Ed25519 keys
+parse-price-info-failed = Could not read the price info from the walrus CLI output: { $error }
+invalid-blob-id = Invalid blob ID: { $id }
+missing-blob-id = The upload result has no blob ID
+create-download-dir-failed = Could not create the download directory: { $error }
+aggregator-unreachable = Could not reach the aggregator: { $error }
+aggregator-error-status = The aggregator returned an error status: { $status }
+blob-not-found = Blob { $id } does not exist or has expired
@@ -0,0 +1,509 @@
+# 简体中文消息目录
+# 键名使用小写字母和连字符，参数写作 { $name }
+
+## 语言
+language-auto = 跟随系统
+
+## 上传
+files-staged = 待上传文件: { $count } 个
+no-file-selected = 未选择文件。
+select-file-first = 请先选择文件。
+nothing-to-upload-in = { $path } 中没有可上传的文件
+dropped-files-rejected = 拖放的 { $count } 个文件无法上传：{ $error }
+dropped-files-partly-rejected = { $status }；拖放的 { $count } 个文件无法上传：{ $error }
+upload-succeeded = 上传成功，ID: { $id }
+upload-failed = 上传失败: { $error }
+upload-queue-remaining = { $status }，队列中还有 { $count } 个文件
+upload-queued = 已加入上传队列，等待上传的文件: { $count } 个
+uploading = 正在上传 { $name }...
+
+## 下载
+downloading-to = 正在下载 { $name } 到 { $path }...
+downloading-id-to = 正在下载文件 (ID: { $id }) 到 { $path }...
+no-download-path = 未选择下载路径。
+enter-download-id = 请输入要下载的文件 ID。
+download-failed = 下载失败: { $error }
+download-conflict = 文件已存在: { $path }，请选择处理方式
+batch-download-nothing-selected = 没有选择任何文件进行批量下载。
+batch-download-started = 正在批量下载 { $count } 个文件...
+no-batch-download-path = 未选择批量下载路径。
+
+## 文件列表
+file-id-not-found = 找不到文件 ID: { $id }
+id-already-listed = 文件列表中已有 ID: { $id }
+probing-blob = 正在检查 blob { $id } 是否可用...
+blob-added-with-size = 已添加 { $name } ({ $size } 字节)
+blob-added = 已添加 { $name }
+add-blob-failed = 添加失败: { $error }
+file-deleted = 已从 Walrus 删除 blob 并移除记录，ID: { $id }
+keystore-loaded = 已从密钥库读取 { $count } 个地址
+delete-failed = 从 Walrus 删除失败: { $error }
+deleting-from-walrus = 正在从 Walrus 删除 { $name }...
+id-copied = 文件 ID 已复制到剪贴板: { $id }
+batch-delete-nothing-selected = 没有选择任何文件进行批量删除。
+nothing-selected = 没有选择任何文件。
+
+## 回收站
+moved-to-trash = 已将 { $count } 个文件记录移到回收站，可在回收站中恢复。
+restored-from-trash = 已从回收站恢复 { $name }
+trash-purged = 已从回收站永久删除 { $count } 个记录。
+trash-empty = 回收站是空的。
+trash-entry-not-found = 回收站中找不到 ID: { $id }
+
+## 预览与缩略图
+encrypted-preview-too-large = 加密文件过大，无法预览
+image-too-large = 图片过大
+
+## 分享
+share-link-copied = 分享链接已复制到剪贴板: { $url }
+share-link-failed = 无法生成分享链接: { $error }
+select-files-to-share = 请先选择要分享的文件。
+publishing-bundle = 正在发布包含 { $count } 个文件的分享包...
+bundle-published = 分享包已发布并复制到剪贴板，清单 ID: { $id }
+publish-bundle-failed = 发布分享包失败: { $error }
+loading-bundle = 正在读取分享包 { $id }...
+bundle-loaded = 分享包中有 { $count } 个文件
+open-bundle-failed = 打开分享包失败: { $error }
+import-source-bundle = 分享包 { $id }
+bundle-download-started = 正在下载分享包中的 { $count } 个文件...
+
+## 身份密钥
+public-key-copied = 公钥已复制到剪贴板
+contact-added = 已添加联系人 { $name }
+
+## 配置导入导出
+config-exported = 配置文件已导出到: { $path }
+config-exported-without-keys = 配置文件已导出到: { $path }。其中 { $count } 个加密文件的密钥未导出，请通过分享包分享这些文件。
+export-config-failed = 导出配置文件失败: { $error }
+serialize-files-failed = 序列化文件列表失败: { $error }
+no-export-path = 未选择导出路径。
+parse-import-failed = 解析导入文件失败: { $error }
+read-import-failed = 读取导入文件失败: { $error }
+no-import-file = 未选择导入文件。
+config-passphrase-required-for-upload = 请先输入配置密码，远程配置将加密后上传。
+uploading-config = 正在加密并上传配置...
+upload-config-failed = 配置上传失败: { $error }
+config-uploaded = 配置上传成功，ID: { $id } 已复制blobId
+enter-config-id = 请输入要加载的配置 ID。
+loading-config = 正在从 ID: { $id } 加载配置...
+config-is-encrypted = 该配置已加密，请输入配置密码。
+load-config-failed = 加载配置失败: { $error }
+enter-config-passphrase = 请输入配置密码。
+decrypting-config = 正在解密配置...
+decrypt-config-failed = 解密配置失败: { $error }
+config-load-cancelled = 已取消加载配置。
+config-imported = 配置已从 { $source } 导入（{ $mode }），共 { $count } 个文件记录。
+import-cancelled = 已取消导入。
+import-undone = 已撤销最近一次导入。
+nothing-to-undo = 没有可撤销的导入。
+import-source-config = 配置 ID: { $id }
+import-staged = 已读取 { $source } 中的 { $count } 个文件记录，请确认导入方式。
+
+## 配置历史
+loading-snapshot = 正在读取配置快照 { $id }...
+comparing-snapshot = 正在对比配置快照 { $id }。
+import-source-snapshot = 配置快照: { $id }
+load-snapshot-failed = 读取配置快照失败: { $error }
+
+## 同步
+sync-enabled = 已开启自动同步，每 { $minutes } 分钟同步一次。
+sync-disabled = 已关闭自动同步。
+sync-pointer-set = 同步指针文件: { $path }
+no-sync-pointer-selected = 未选择同步指针文件。
+sync-completed = 同步完成。
+sync-completed-with-conflicts = 同步完成，有 { $count } 个冲突，详情见同步面板。
+sync-failed = 同步失败: { $error }
+sync-pointer-required = 请先设置同步指针文件。
+sync-passphrase-required = 同步需要配置密码，请先输入配置密码。
+syncing = 正在同步...
+
+## 工作区
+sync-busy-switching-workspace = 正在同步，请稍后再切换工作区。
+transfer-busy-switching-workspace = 仍有上传或 blob 探测在进行，请完成后再切换工作区。
+workspace-switched = 已切换到工作区: { $name }
+workspace-created = 已创建工作区: { $name }
+create-workspace-failed = 创建工作区失败: { $error }
+cannot-delete-active-workspace = 不能删除当前使用的工作区。
+workspace-deleted = 已删除工作区: { $name }
+network-profile-saved = 工作区 { $name } 的网络配置已保存。
+invalid-network-profile = 网络配置无效: { $error }
+select-target-workspace = 请先选择目标工作区。
+target-is-current-workspace = 目标工作区与当前工作区相同。
+entries-copied = 已复制 { $count } 个文件记录到工作区 { $target }（新增 { $added } 个）。
+entries-moved = 已移动 { $count } 个文件记录到工作区 { $target }（新增 { $added } 个）。
+
+## 界面：标题栏
+app-title = Walrus云盘
+window-title = walrus云盘 - { $workspace }
+import-config = 导入配置
+export-config = 导出配置
+upload-config = 上传配置
+config-history = 配置历史
+sync = 同步
+syncing-short = 同步中...
+undo-import = 撤销导入
+workspaces = 工作区
+identity-keys = 身份密钥
+open-command-palette = 命令 (Ctrl+K)
+trash-button = 回收站 ({ $count })
+
+## 界面：上传区域
+choose-files = 选择文件
+no-files-chosen = 未选择文件（可多选）
+files-chosen = 已选择 { $count } 个文件
+upload-all = 全部上传 ({ $count })
+clear = 清空
+wrap-metadata = 附带文件名和类型（按 ID 下载时可恢复）
+encrypt-uploads = 加密上传
+deletable-uploads = 可删除存储
+upload-progress = 上传进度: { $percent }%
+upload-progress-queued = 上传进度: { $percent }%  等待上传: { $count } 个文件
+drop-hint = 也可以把文件或文件夹直接拖到窗口中上传
+price-unknown = 尚未查询存储价格
+price-loading = 正在查询存储价格…
+price-ready = 选择文件后显示预计费用
+price-unavailable = 无法获取存储价格: { $error }
+cost-estimate-total = 预计编码后 { $encoded }，费用约 { $cost }{ $skipped ->
+    [0] {""}
+   *[other] （{ $skipped } 个文件大小或周期无效，未计入）
+}
+refresh-price = 刷新价格
+workspace-spend = 本工作区累计存储费用: { $cost }（{ $count } 次上传）
+file-name = 文件名
+tags = 标签，用逗号分隔
+epochs = 周期
+remove = 移除
+drop-release = 松开鼠标即可上传
+drop-details = 支持多个文件和文件夹，文件夹中的文件会全部加入上传队列
+
+## 界面：文件列表
+search-files = 搜索文件...
+list-view = 列表视图
+grid-view = 网格视图
+file-id = 文件 ID
+uploaded-at = 上传时间
+actions = 操作
+copy-id = 复制 ID
+share = 分享
+preview = 预览
+download = 下载
+delete = 删除
+loading = 加载中...
+file = 文件
+invert-selection = 反选
+selection-summary = 已选中 { $count } / { $total } 个文件，共 { $size }
+selection-unknown-sizes = （{ $count } 个文件大小未知）
+batch-delete = 批量删除
+batch-download = 批量下载
+share-selected = 分享选中
+share-selected-encrypted = 加密分享选中（{ $count } 位接收人）
+target-workspace = 目标工作区
+copy-to-workspace = 复制到工作区
+move-to-workspace = 移动到工作区
+
+## 界面：下载区域
+enter-file-id = 输入文件 ID
+download-by-id = 从 ID 下载
+load-config = 加载配置
+open-bundle = 打开分享包
+when-file-exists = 文件已存在时:
+file-name-optional = 文件名（可选）
+tags-optional = 标签，用逗号分隔（可选）
+add-to-list = 添加到列表
+config-passphrase = 配置密码
+remote-config-encrypted = 该远程配置已加密，请输入配置密码
+decrypt = 解密
+cancel = 取消
+close = 关闭
+
+## 界面：下载冲突
+download-exists-title = 下载的文件已存在
+remember-conflict-choice = 记住这个选择，作为以后下载的默认处理方式（也用于处理其余冲突）
+conflict-file = { $path } ({ $size } 字节)
+conflicts-remaining = 之后还有 { $count } 个冲突等待处理
+
+## 界面：分享
+bundle-summary-with-size = { $count } 个文件，共 { $size }，创建于 { $created_at }
+bundle-summary = { $count } 个文件，创建于 { $created_at }
+bundle-title = 分享包 { $id }
+download-all = 全部下载
+import-to-list = 导入到列表
+qr-code-failed = 无法生成二维码
+share-title = 分享 { $name }
+share-encrypted-use-bundle = 这个文件是加密上传的，链接或二维码只能下载到密文。请选中文件并选择接收人发布分享包，文件密钥会按接收人加密放在分享包中。
+share-link = 分享链接
+include-name-in-link = 链接中附带文件名
+copy-link = 复制链接
+scan-qr-hint = 用手机扫描二维码即可下载
+
+## 界面：预览
+loading-preview = 正在加载预览...
+pdf-version = PDF 文档，版本 { $version }
+pdf-pages = 共 { $count } 页
+pdf-pages-unknown = 页数未知
+pdf-title = 标题：{ $title }
+pdf-author = 作者：{ $author }
+pdf-preview-hint = 这里不渲染页面，请下载后查看。
+preview-failed = 预览失败: { $error }
+preview-truncated = { $name }（超过预览上限，仅显示开头部分）
+blob-id-detail = Blob ID: { $id }
+blob-object-id = Blob 对象: { $id }
+blob-cost = 上传时支付的存储费用: { $cost }
+owned-by = 持有者: { $address }
+owned-by-workspace-address = 持有者: { $address }（本工作区的地址）
+owned-by-publisher = 持有者: 发布器（上传时未设置地址）
+blob-object-unknown = 持有者: 未知（blob 已存在或为记录持有者之前的旧记录）
+
+## 界面：导入预览与快照对比
+changes-added = 新增 { $count } 个
+changes-removed = 移除 { $count } 个
+changes-conflicts = 冲突 { $count } 个
+changes-unchanged = 未变化 { $count } 个
+change-conflict = ! { $id }: 本地 { $local } / { $label } { $incoming }
+keep-incoming = 采用导入
+keep-local = 保留本地
+change-source-import = 导入
+change-source-snapshot = 快照
+import-preview-title = 导入预览: { $source }
+import-mode = 导入方式
+confirm-import = 确认导入
+
+## 界面：配置历史
+no-snapshots = 还没有发布过配置快照。
+none = 无
+snapshot-file-count = { $count } 个文件
+previous-snapshot = 上一版本: { $id }
+snapshot-epochs = 存储 { $count } 个周期
+snapshot-epochs-label = 新快照和分享清单的存储周期数
+compare = 对比
+restore = 恢复
+config-history-title = 配置历史（共 { $count } 个快照）
+snapshot-diff-title = 快照对比: { $id } ({ $created_at })
+restore-snapshot = 恢复此快照
+
+## 界面：工作区面板
+current = 当前
+switch = 切换
+new-workspace-name = 新工作区名称
+create = 新建
+network-profile-title = 工作区 { $name } 的网络配置
+aggregator-url = 聚合器地址
+publisher-url = 发布器地址
+chain-settings-title = 链上操作（从 Walrus 删除 blob 时使用 walrus 命令行客户端及其钱包）
+walrus-cli-path = walrus 可执行文件
+walrus-config-path = walrus 客户端配置文件（可选）
+sui-address-placeholder = 接收上传 blob 对象的 Sui 地址（0x…）
+sui-address-unset = 未设置地址：上传的 blob 对象由发布器持有，之后无法延期、删除或转移。
+sui-address-set = 新上传的 blob 对象会发送到这个地址。
+keystore-path-placeholder = 本地 Sui 密钥库（可选，例如 ~/.sui/sui_config/sui.keystore）
+load-keystore = 读取密钥库
+choose-keystore-address = 从密钥库中选择地址
+save-network-profile = 保存网络配置
+
+## 界面：身份密钥面板
+my-public-key = 我的公钥
+fingerprint = 指纹: { $value }
+copy-public-key = 复制公钥
+identity-unavailable = 身份密钥不可用
+contacts-title = 通讯录（共 { $count } 位联系人）
+contacts-empty = 通讯录为空，添加对方的公钥后即可加密分享
+share-to = 分享给
+contact-name = 联系人名称
+contact-public-key = 对方的公钥（ws25519:...）
+add = 添加
+
+## 界面：命令面板
+command-palette = 命令面板
+command-palette-placeholder = 输入命令名称...
+command-palette-hint = 回车执行第一项，Esc 关闭。也可以直接使用快捷键：Ctrl+O 选择文件、Ctrl+F 搜索、Ctrl+A 全选、Delete 删除选中、Ctrl+Shift+D 下载选中。
+no-matching-commands = 没有匹配的命令
+command-choose-files = 选择要上传的文件
+command-upload-all = 全部上传
+command-focus-search = 搜索文件
+command-select-all = 全选当前列表
+command-invert-selection = 反选当前列表
+command-delete-selection = 删除选中的文件
+command-download-selection = 下载选中的文件
+command-share-selection = 分享选中的文件
+command-toggle-grid-view = 切换列表/网格视图
+command-download-by-id = 从 ID 下载
+command-open-bundle = 打开分享包
+command-import-config = 导入配置
+command-export-config = 导出配置
+command-upload-config = 上传配置
+command-load-config = 加载远程配置
+command-undo-import = 撤销导入
+command-config-history = 配置历史
+command-sync-panel = 同步设置
+command-sync-now = 立即同步
+command-workspaces = 管理工作区
+command-identity-keys = 身份密钥与通讯录
+command-trash = 回收站
+command-activity-log = 活动日志
+
+## 界面：确认对话框
+confirm-title = 请确认
+confirm-delete-entries = 确定要删除 { $count } 个文件记录吗？记录会移到回收站，{ $days } 天后自动清除，在此之前可以恢复。Walrus 上的 blob 不受影响。
+confirm-delete-entries-kept = 确定要删除 { $count } 个文件记录吗？记录会移到回收站并一直保留，直到手动清除。Walrus 上的 blob 不受影响。
+confirm-delete-from-walrus = 确定要从 Walrus 删除这个 blob 吗？删除后任何人都无法再读取它，记录也会从列表中移除，此操作无法撤销。
+confirm-replace-import = 替换导入会从当前列表中移除 { $count } 个记录（移除的记录会放入回收站）。确定继续吗？
+confirm-purge-trash = 确定要从回收站永久删除 { $count } 个记录吗？此操作无法撤销，删除后将无法找回对应的 blob ID。
+confirm-delete-workspace = 确定要删除工作区 { $name } 吗？其中的文件列表、设置和回收站都会被删除，此操作无法撤销。
+confirm-shorten-retention = 把回收站保留期改为 { $days } 天会立即永久删除 { $count } 个已超过该期限的记录。确定继续吗？
+confirm-more-entries = ……以及另外 { $count } 个记录
+move-to-trash = 移到回收站
+replace-list = 替换列表
+delete-permanently = 永久删除
+delete-workspace = 删除工作区
+shorten-retention = 缩短并删除
+forget = 忘记
+delete-from-walrus = 从 Walrus 删除
+
+## 界面：回收站
+trash-title = 回收站（共 { $count } 个记录）
+trash-retention-days = 保留天数（0 表示永久保留，按回车应用）
+invalid-retention-days = 无效的保留天数：{ $input }
+empty-trash = 清空回收站
+trash-is-empty = 回收站中没有记录。
+deleted-at = 删除于 { $time }
+days-left = 剩余 { $days } 天
+kept-forever = 永久保留
+
+## 界面：活动日志
+activity-log-button = 活动日志
+activity-title = 活动日志（显示 { $shown } / { $count } 条）
+activity-empty = 没有符合条件的活动。
+export-activity = 导出 CSV
+activity-all-types = 全部类型
+activity-all-results = 全部结果
+activity-succeeded = 成功
+activity-failed = 失败
+activity-upload = 上传
+activity-download = 下载
+activity-delete = 删除
+activity-import = 导入
+activity-config-push = 推送配置
+activity-sync = 同步
+activity-bundle-subject = 分享包（{ $count } 个文件）
+activity-config-subject = 配置快照（{ $count } 个记录）
+activity-entries-subject = { $count } 个记录
+activity-import-detail = { $mode }，导入 { $count } 个记录
+activity-exported = 已导出 { $count } 条活动记录到: { $path }
+export-activity-failed = 导出活动日志失败: { $error }
+
+## 界面：同步面板
+last-synced = 上次同步: { $at } (快照 { $id })
+never-synced = 尚未同步
+deleted = 已删除
+last-sync-conflicts = 最近一次同步的冲突: { $count } 个
+sync-conflict = ! { $id }: 本地 { $local } / 远程 { $remote } -> 保留 { $kept }
+sync-now = 立即同步
+multi-device-sync = 多设备同步
+auto-sync = 自动同步
+sync-pointer-placeholder = 同步指针文件路径（例如共享目录中的文件）
+choose = 选择
+sync-interval-minutes = 同步间隔（分钟）
+
+## 下载冲突处理与导入方式
+conflict-policy-ask = 询问
+conflict-policy-overwrite = 覆盖
+conflict-policy-skip = 跳过
+conflict-policy-rename = 自动重命名
+conflict-policy-compare-hash = 比较哈希，相同则跳过
+download-saved = 下载成功到: { $path }
+download-skipped = 文件已存在，已跳过: { $path }
+download-identical = 已存在内容相同的文件，已跳过: { $path }
+download-exists = 文件已存在: { $path }
+invalid-file-name = 文件名无效: { $name }
+no-free-file-name = 无法为 { $path } 找到可用的文件名
+import-mode-replace = 替换
+import-mode-merge = 合并
+import-mode-merge-keep-newer = 合并(保留较新)
+
+## 错误：文件内容
+serialize-metadata-failed = 序列化文件元数据失败: { $error }
+envelope-corrupted = 文件元数据信封已损坏
+parse-metadata-failed = 解析文件元数据失败: { $error }
+envelope-mismatch = 文件内容与元数据中的大小或哈希不一致
+encrypt-file-failed = 加密文件失败: { $error }
+not-an-encrypted-file = 文件不是加密格式
+encrypted-file-corrupted = 加密文件已损坏
+invalid-file-key = 文件密钥无效
+wrong-file-key = 文件密钥不正确或文件已损坏
+missing-file-key = 该文件已加密，但没有对应的文件密钥
+hex-view-truncated = 仅显示前 { $limit } 字节
+qr-code-error = 无法生成二维码: { $error }
+save-thumbnail-failed = 保存缩略图失败: { $error }
+decode-image-failed = 无法解析图片: { $error }
+empty-staged-name = { $path } 的文件名不能为空
+invalid-epochs = 存储周期必须是 1 到 { $max } 之间的整数: { $input }
+
+## 错误：分享包与身份密钥
+not-a-bundle = 该 ID 不是分享包清单
+bundle-version-too-new = 分享包版本 { $version } 过新，请升级应用后再打开
+serialize-bundle-failed = 序列化分享包失败: { $error }
+encrypted-bundle-needs-recipients = 选中的文件已加密，请先选择分享对象
+sealed-bundle-needs-identity = 这是加密分享包，需要身份密钥才能打开
+bundle-corrupted = 分享包内容已损坏
+identity-corrupted = 身份私钥已损坏
+invalid-public-key = 公钥格式无效，应以 { $prefix } 开头
+empty-contact-name = 联系人名称不能为空
+duplicate-contact = 该公钥已在通讯录中
+encrypt-failed = 加密失败: { $error }
+no-recipients = 至少需要一个接收人
+not-a-recipient = 此分享不是发给你的身份密钥的
+recipient-corrupted = 接收人条目已损坏
+unwrap-key-failed = 无法解开内容密钥
+decrypt-failed = 解密失败，内容可能已损坏
+
+## 错误：配置
+unsupported-kdf = 不支持的密钥派生算法: { $algorithm }
+decode-salt-failed = 无法解码盐值: { $error }
+invalid-kdf-params = 无效的密钥派生参数: { $error }
+kdf-params-too-expensive = 配置要求的密钥派生参数过高（m_cost { $m_cost }，t_cost { $t_cost }，p_cost { $p_cost }），已拒绝
+derive-key-failed = 派生密钥失败: { $error }
+empty-config-passphrase = 配置密码不能为空
+encrypt-config-failed = 加密配置失败: { $error }
+serialize-encrypted-config-failed = 序列化加密配置失败: { $error }
+parse-encrypted-config-failed = 解析加密配置失败: { $error }
+not-an-encrypted-config = 不是加密配置
+unsupported-config-version = 不支持的加密配置版本: { $version }
+decode-nonce-failed = 无法解码随机数: { $error }
+invalid-nonce-length = 加密配置的随机数长度无效
+decode-ciphertext-failed = 无法解码密文: { $error }
+wrong-passphrase = 密码错误或配置已损坏
+config-not-utf8 = 无法将配置数据解码为 UTF-8: { $error }
+parse-config-failed = 解析配置数据失败: { $error }
+serialize-config-failed = 序列化配置失败: { $error }
+snapshot-is-encrypted = 该快照已加密，请先输入配置密码
+
+## 错误：同步与工作区
+read-sync-pointer-failed = 读取同步指针失败: { $error }
+parse-sync-pointer-failed = 解析同步指针失败: { $error }
+serialize-sync-pointer-failed = 序列化同步指针失败: { $error }
+create-pointer-dir-failed = 无法创建指针目录: { $error }
+write-sync-pointer-failed = 写入同步指针失败: { $error }
+empty-workspace-name = 工作区名称不能为空
+workspace-name-too-long = 工作区名称不能超过 { $max } 个字符
+workspace-name-starts-with-dot = 工作区名称不能以 . 开头
+workspace-name-invalid-char = 工作区名称不能包含字符 { $char }
+workspace-exists = 工作区 { $name } 已存在
+delete-workspace-failed = 删除工作区失败: { $error }
+
+## 错误：网络
+blob-not-deletable = { $name } 不是以可删除方式存储的，无法从 Walrus 删除，只能等到期或从本地忘记。
+blob-not-owned = { $name } 的 blob 对象不属于当前配置的 Sui 地址，本机钱包无法删除。
+invalid-object-id = 无效的 Sui 对象 ID：{ $id }
+walrus-cli-unavailable = 无法运行 walrus 命令行客户端 { $binary }: { $error }
+walrus-cli-failed = walrus 命令执行失败: { $error }
+invalid-sui-address = 无效的 Sui 地址: { $address }（应为 0x 加 64 位十六进制）
+invalid-keystore = 不是有效的 Sui 密钥库: { $error }
+read-keystore-failed = 读取密钥库失败: { $error }
+keystore-has-no-ed25519-keys = 密钥库中没有 Ed25519 密钥
+parse-price-info-failed = 无法从 walrus 命令行输出中读取价格信息: { $error }
+invalid-blob-id = 无效的 blob ID: { $id }
+missing-blob-id = 上传结果中缺少 blob ID
+create-download-dir-failed = 无法创建下载目录: { $error }
+aggregator-unreachable = 无法连接聚合器: { $error }
+aggregator-error-status = 聚合器返回错误状态: { $status }
+blob-not-found = blob { $id } 不存在或已过期
@@ -0,0 +1,291 @@
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+// 活动日志最多保留的记录数，超出时丢弃最早的记录
+pub const MAX_RECORDS: usize = 5000;
+const CSV_HEADER: &str = "time,type,result,subject,detail,duration_ms,bytes";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActivityKind {
+    Upload,
+    Download,
+    Delete,
+    Import,
+    ConfigPush,
+    Sync,
+}
+
+impl ActivityKind {
+    pub const ALL: [ActivityKind; 6] = [
+        ActivityKind::Upload,
+        ActivityKind::Download,
+        ActivityKind::Delete,
+        ActivityKind::Import,
+        ActivityKind::ConfigPush,
+        ActivityKind::Sync,
+    ];
+
+    // 英文标识，用于 CSV 导出和消息目录的键后缀
+    fn id(self) -> &'static str {
+        match self {
+            ActivityKind::Upload => "upload",
+            ActivityKind::Download => "download",
+            ActivityKind::Delete => "delete",
+            ActivityKind::Import => "import",
+            ActivityKind::ConfigPush => "config-push",
+            ActivityKind::Sync => "sync",
+        }
+    }
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", t!(&format!("activity-{}", self.id())))
+    }
+}
+
+// 一条活动记录；失败的操作同时作为错误记录，detail 中保存错误信息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityRecord {
+    pub at: String,
+    pub kind: ActivityKind,
+    pub success: bool,
+    pub subject: String, // 文件名、blob ID 或配置来源
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
+impl ActivityRecord {
+    pub fn new(
+        kind: ActivityKind,
+        subject: impl Into<String>,
+        success: bool,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            kind,
+            success,
+            subject: subject.into(),
+            detail: detail.into(),
+            duration_ms: None,
+            bytes: None,
+        }
+    }
+
+    pub fn with_duration(mut self, elapsed: Duration) -> Self {
+        self.duration_ms = Some(elapsed.as_millis() as u64);
+        self
+    }
+
+    pub fn with_bytes(mut self, bytes: Option<u64>) -> Self {
+        self.bytes = bytes;
+        self
+    }
+}
+
+pub fn append(log: &mut Vec<ActivityRecord>, record: ActivityRecord) {
+    log.push(record);
+    if log.len() > MAX_RECORDS {
+        log.drain(..log.len() - MAX_RECORDS);
+    }
+}
+
+// 后台任务的结果，附带操作对象和耗时
+#[derive(Debug, Clone)]
+pub struct Timed<T> {
+    pub subject: String,
+    pub elapsed: Duration,
+    pub result: T,
+}
+
+pub async fn timed<T>(subject: String, task: impl Future<Output = T>) -> Timed<T> {
+    let started = Instant::now();
+    let result = task.await;
+    Timed {
+        subject,
+        elapsed: started.elapsed(),
+        result,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KindFilter {
+    #[default]
+    All,
+    Only(ActivityKind),
+}
+
+impl KindFilter {
+    pub fn options() -> Vec<KindFilter> {
+        std::iter::once(KindFilter::All)
+            .chain(ActivityKind::ALL.into_iter().map(KindFilter::Only))
+            .collect()
+    }
+}
+
+impl fmt::Display for KindFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KindFilter::All => write!(f, "{}", t!("activity-all-types")),
+            KindFilter::Only(kind) => kind.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultFilter {
+    #[default]
+    All,
+    Succeeded,
+    Failed,
+}
+
+impl ResultFilter {
+    pub const ALL: [ResultFilter; 3] = [
+        ResultFilter::All,
+        ResultFilter::Succeeded,
+        ResultFilter::Failed,
+    ];
+}
+
+impl fmt::Display for ResultFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            ResultFilter::All => "activity-all-results",
+            ResultFilter::Succeeded => "activity-succeeded",
+            ResultFilter::Failed => "activity-failed",
+        };
+        write!(f, "{}", t!(key))
+    }
+}
+
+// 按类型和结果筛选，最新的记录在前
+pub fn filter(
+    log: &[ActivityRecord],
+    kind: KindFilter,
+    result: ResultFilter,
+) -> Vec<&ActivityRecord> {
+    log.iter()
+        .rev()
+        .filter(|record| match kind {
+            KindFilter::All => true,
+            KindFilter::Only(kind) => record.kind == kind,
+        })
+        .filter(|record| match result {
+            ResultFilter::All => true,
+            ResultFilter::Succeeded => record.success,
+            ResultFilter::Failed => !record.success,
+        })
+        .collect()
+}
+
+// 含逗号、引号或换行的字段用双引号包起来，内部引号写两遍。
+// 文件名和错误信息可能由他人控制，以 = + - @ 开头的字段加上 ' 前缀，
+// 避免在电子表格中被当作公式执行
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+pub fn to_csv(records: &[&ActivityRecord]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for record in records {
+        let fields = [
+            record.at.clone(),
+            record.kind.id().to_string(),
+            if record.success { "ok" } else { "failed" }.to_string(),
+            record.subject.clone(),
+            record.detail.clone(),
+            record
+                .duration_ms
+                .map(|ms| ms.to_string())
+                .unwrap_or_default(),
+            record
+                .bytes
+                .map(|bytes| bytes.to_string())
+                .unwrap_or_default(),
+        ];
+        let line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filtered_records_export_as_csv() {
+        let mut log = Vec::new();
+        append(
+            &mut log,
+            ActivityRecord::new(ActivityKind::Upload, "a.txt", true, "blob-a")
+                .with_duration(Duration::from_millis(1500))
+                .with_bytes(Some(42)),
+        );
+        append(
+            &mut log,
+            ActivityRecord::new(ActivityKind::Download, "b, \"c\".txt", false, "timeout"),
+        );
+        append(
+            &mut log,
+            ActivityRecord::new(
+                ActivityKind::Upload,
+                "d.txt",
+                false,
+                "HTTP 500\nretry later",
+            ),
+        );
+
+        assert_eq!(filter(&log, KindFilter::All, ResultFilter::All).len(), 3);
+        let failed_uploads = filter(
+            &log,
+            KindFilter::Only(ActivityKind::Upload),
+            ResultFilter::Failed,
+        );
+        assert_eq!(failed_uploads.len(), 1);
+        assert_eq!(failed_uploads[0].subject, "d.txt");
+        assert_eq!(
+            filter(&log, KindFilter::All, ResultFilter::Succeeded)[0].subject,
+            "a.txt"
+        );
+
+        let csv = to_csv(&filter(&log, KindFilter::All, ResultFilter::All));
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].ends_with(",upload,failed,d.txt,\"HTTP 500"));
+        assert!(csv.contains(",download,failed,\"b, \"\"c\"\".txt\",timeout,,\n"));
+        assert!(csv.ends_with(",upload,ok,a.txt,blob-a,1500,42\n"));
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("-1+1"), "'-1+1");
+
+        let record = log[0].clone();
+        for _ in 0..MAX_RECORDS {
+            append(&mut log, record.clone());
+        }
+        assert_eq!(log.len(), MAX_RECORDS);
+        assert_eq!(log[0], record);
+        assert_eq!(KindFilter::options().len(), ActivityKind::ALL.len() + 1);
+    }
+}
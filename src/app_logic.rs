@@ -1,14 +1,55 @@
 use directories::UserDirs;
 use iced::Command;
+use iced::widget::text_input;
 use rfd::AsyncFileDialog;
 use std::fs;
-use std::path::PathBuf; // 引入 fs 模块
+use std::path::{Path, PathBuf};
 
 use crate::Message;
 use crate::WalrusStore; // 需要引入 WalrusStore 结构体
-use crate::data::FileEntry;
-use crate::file_management::save_file_entries; // 移除 get_files_json_path 导入
-use crate::walrus_api::WalrusApi; // 引入 WalrusApi
+use crate::activity::{self, ActivityKind, ActivityRecord, timed};
+use crate::blob_delete::{WalrusCli, can_delete, delete_from_walrus};
+use crate::blob_envelope;
+use crate::bundle::{BundleEntry, ShareBundle, fetch_bundle, publish_bundle};
+use crate::commands::{matching_commands, palette_input_id, search_input_id};
+use crate::config_crypto::{decrypt_config, is_encrypted_config};
+use crate::config_history::{
+    ConfigSnapshot, SnapshotAction, fetch_snapshot, parse_config_payload, publish_snapshot,
+};
+use crate::config_merge::{ImportMode, PendingImport, apply_import, preview_import};
+use crate::confirm::ConfirmAction;
+use crate::cost::{PriceSource, PriceStatus};
+use crate::data::{FileEntry, parse_tags};
+use crate::download::{DownloadOutcome, save_download};
+use crate::file_crypto::decrypt_if_needed;
+use crate::file_management::{
+    collect_upload_files, load_activity, load_config_history, load_file_entries, load_settings,
+    load_spend, load_sync_state, load_trash, load_user_themes, save_activity, save_config_history,
+    save_contacts, save_file_entries, save_preferences, save_settings, save_spend, save_sync_state,
+    save_trash,
+};
+use crate::i18n::{set_language, t};
+use crate::identity::validate_contact;
+use crate::preview::{PREVIEW_SIZE_LIMIT, PreviewBody, PreviewPane, prepare_preview};
+use crate::selection::{self, visible_ids};
+use crate::share::{QrMatrix, ShareDialog};
+use crate::staging::{StagedUpload, UploadJob, parse_epochs};
+use crate::sync::{SyncState, run_sync, three_way_merge};
+use crate::theme::{find_theme, merge_user_themes, set_palette};
+use crate::thumbnail::{
+    MAX_PENDING_THUMBNAILS, ThumbnailStatus, cache_thumbnail, cached_thumbnail, is_image_entry,
+};
+use crate::trash::{
+    self, count_expired, move_to_trash, purge_expired, restore_from_trash, trash_entries,
+};
+use crate::wallet::{read_keystore, validate_sui_address};
+use crate::walrus_api::{UploadOptions, WalrusApi, validate_blob_id};
+use crate::workspace::{
+    TransferMode, copy_entries_to_workspace, create_workspace, delete_workspace, list_workspaces,
+    save_active_workspace,
+};
+use chrono::Utc;
+use walrus_rs::WalrusClient;
 
 pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<Message> {
     match message {
@@ -24,45 +65,147 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
 
                 let pick_result = AsyncFileDialog::new()
                     .set_directory(initial_directory)
-                    .pick_file()
+                    .pick_files()
                     .await;
-                Message::FileSelected(pick_result.map(|handle| handle.path().to_path_buf()))
+                Message::FilesSelected(pick_result.map(|handles| {
+                    handles
+                        .iter()
+                        .map(|handle| handle.path().to_path_buf())
+                        .collect()
+                }))
             },
             |msg| msg,
         ),
-        Message::FileSelected(path_opt) => {
-            if let Some(path) = path_opt {
-                app_state.upload_file_path = path.to_string_lossy().into_owned();
-                app_state.status_message = format!("已选择文件: {}", app_state.upload_file_path);
-            } else {
-                app_state.upload_file_path = String::new();
-                app_state.status_message = "未选择文件。".into();
+        Message::FilesSelected(paths_opt) => {
+            match paths_opt {
+                Some(paths) if !paths.is_empty() => {
+                    let default_epochs = app_state.settings.upload.default_epochs;
+                    for path in paths {
+                        if !app_state.upload_staging.iter().any(|s| s.path == path) {
+                            app_state
+                                .upload_staging
+                                .push(StagedUpload::new(path, default_epochs));
+                        }
+                    }
+                    app_state.status_message =
+                        t!("files-staged", count = app_state.upload_staging.len());
+                    // 第一次暂存文件时查询价格，用于显示预计费用
+                    if matches!(app_state.price_status, PriceStatus::Unknown) {
+                        return fetch_prices(app_state);
+                    }
+                }
+                _ => {
+                    app_state.status_message = t!("no-file-selected");
+                }
+            }
+            Command::none()
+        }
+        Message::StagedNameChanged(index, name) => {
+            if let Some(staged) = app_state.upload_staging.get_mut(index) {
+                staged.name = name;
+            }
+            Command::none()
+        }
+        Message::StagedTagsChanged(index, tags) => {
+            if let Some(staged) = app_state.upload_staging.get_mut(index) {
+                staged.tags = tags;
+            }
+            Command::none()
+        }
+        Message::StagedEpochsChanged(index, epochs) => {
+            if let Some(staged) = app_state.upload_staging.get_mut(index) {
+                staged.epochs = epochs;
+            }
+            Command::none()
+        }
+        Message::RemoveStagedFile(index) => {
+            if index < app_state.upload_staging.len() {
+                app_state.upload_staging.remove(index);
             }
             Command::none()
         }
+        Message::ClearStaging => {
+            app_state.upload_staging.clear();
+            Command::none()
+        }
         Message::UploadButtonPressed => {
-            if app_state.upload_file_path.is_empty() {
-                app_state.status_message = "请先选择一个文件。".into();
+            if app_state.upload_staging.is_empty() {
+                app_state.status_message = t!("select-file-first");
                 return Command::none();
             }
-            let file_path = PathBuf::from(&app_state.upload_file_path);
-            let file_name = file_path
-                .file_name()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .into_owned();
-
-            app_state.status_message = format!("正在上传 {}...", file_name);
-            app_state.upload_progress = 0.0;
-
-            let walrus_api = WalrusApi::default(); // 创建 WalrusApi 实例
-            Command::perform(
-                async move { walrus_api.upload_file(file_path).await },
-                move |result| match result {
-                    Ok(id) => Message::UploadComplete(Ok(FileEntry::new(id, file_name.clone()))),
-                    Err(e) => Message::UploadComplete(Err(e)),
-                },
-            )
+            // 全部校验通过后才开始上传，出错时保留暂存列表以便修改
+            let jobs: Result<Vec<UploadJob>, String> = app_state
+                .upload_staging
+                .iter()
+                .map(StagedUpload::to_job)
+                .collect();
+            match jobs {
+                Ok(jobs) => {
+                    app_state.upload_staging.clear();
+                    enqueue_uploads(app_state, jobs)
+                }
+                Err(e) => {
+                    app_state.status_message = e;
+                    Command::none()
+                }
+            }
+        }
+        Message::FilesHovered => {
+            app_state.drop_hover = true;
+            Command::none()
+        }
+        Message::FilesHoveredLeft => {
+            app_state.drop_hover = false;
+            Command::none()
+        }
+        Message::FileDropped(path) => {
+            app_state.drop_hover = false;
+            let files = collect_upload_files(&path);
+            if files.is_empty() {
+                app_state.status_message =
+                    t!("nothing-to-upload-in", path = path.display().to_string());
+                return Command::none();
+            }
+            // 拖放的文件使用默认文件名和存储周期直接上传
+            let default_epochs = app_state.settings.upload.default_epochs;
+            let mut jobs = Vec::new();
+            let mut errors = Vec::new();
+            for path in files {
+                match StagedUpload::new(path.clone(), default_epochs).to_job() {
+                    Ok(job) => jobs.push(job),
+                    Err(e) => errors.push((path, e)),
+                }
+            }
+            let queued = !jobs.is_empty();
+            let command = if !queued {
+                Command::none()
+            } else {
+                enqueue_uploads(app_state, jobs)
+            };
+            // 无法上传的文件（例如设置中的默认存储周期无效）报告数量和第一个错误
+            if let Some((_, first_error)) = errors.first() {
+                app_state.status_message = if queued {
+                    t!(
+                        "dropped-files-partly-rejected",
+                        status = app_state.status_message.as_str(),
+                        count = errors.len(),
+                        error = first_error.as_str()
+                    )
+                } else {
+                    t!(
+                        "dropped-files-rejected",
+                        count = errors.len(),
+                        error = first_error.as_str()
+                    )
+                };
+            }
+            for (path, e) in errors {
+                log_activity(
+                    app_state,
+                    ActivityRecord::new(ActivityKind::Upload, path.display().to_string(), false, e),
+                );
+            }
+            command
         }
         Message::DownloadButtonPressed(id) => {
             Command::perform(async move { id }, Message::TriggerDownloadSelection)
@@ -88,40 +231,44 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
             if let Some(download_path) = path_opt {
                 let file_entry = app_state.files.iter().find(|f| f.id == id).cloned();
                 if let Some(entry) = file_entry {
-                    app_state.status_message = format!(
-                        "正在下载 {} 到 {}...",
-                        entry.name,
-                        download_path.to_string_lossy()
+                    app_state.status_message = t!(
+                        "downloading-to",
+                        name = entry.name.as_str(),
+                        path = download_path.to_string_lossy().into_owned()
                     );
-                    let walrus_api = WalrusApi::default(); // 创建 WalrusApi 实例
+                    let walrus_api = WalrusApi::new(&app_state.settings.network); // 创建 WalrusApi 实例
+                    let policy = app_state.settings.download.conflict_policy;
                     Command::perform(
-                        async move {
+                        timed(entry.name.clone(), async move {
                             walrus_api
-                                .download_file(entry.id.clone(), entry.name.clone(), download_path)
+                                .download_file(
+                                    entry.id.clone(),
+                                    Some(entry.name.clone()),
+                                    download_path,
+                                    policy,
+                                    entry.file_key.clone(),
+                                )
                                 .await
-                        },
+                        }),
                         Message::DownloadComplete,
                     )
                 } else {
-                    app_state.status_message = format!("找不到文件 ID: {}", id);
+                    app_state.status_message = t!("file-id-not-found", id = id.as_str());
                     Command::none()
                 }
             } else {
-                app_state.status_message = "未选择下载路径。".into();
+                app_state.status_message = t!("no-download-path");
                 Command::none()
             }
         }
         Message::DeleteButtonPressed(id) => {
             if app_state.files.iter().any(|f| f.id == id) {
-                // 用户要求delete file不用处理，只需要把这个配置文件的记录删掉即可
-                app_state.files.retain(|f| f.id != id);
-                save_file_entries(&app_state.files);
-                app_state.status_message = format!("文件已从本地记录中删除，ID: {}", id);
-                Command::none()
+                // 删除前先确认，删除的记录放入回收站
+                app_state.pending_confirmation = Some(ConfirmAction::DeleteEntries(vec![id]));
             } else {
-                app_state.status_message = format!("找不到文件 ID: {}", id);
-                Command::none()
+                app_state.status_message = t!("file-id-not-found", id = id.as_str());
             }
+            Command::none()
         }
         Message::DownloadInputChanged(id) => {
             app_state.download_id_input = id;
@@ -130,7 +277,7 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
         Message::DownloadFromInputButtonPressed => {
             let id_to_download = app_state.download_id_input.clone();
             if id_to_download.is_empty() {
-                app_state.status_message = "请输入要下载的文件 ID。".into();
+                app_state.status_message = t!("enter-download-id");
                 return Command::none();
             }
             // 触发文件选择对话框，并将下载ID传递给后续处理
@@ -160,75 +307,421 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
         ),
         Message::DownloadLocationSelectedFromInput(path_opt, id_to_download) => {
             if let Some(download_path) = path_opt {
-                // 现在我们需要从Walrus API获取文件名，或者直接使用一个默认文件名
-                // 简化处理：假设API能根据ID返回文件数据，但文件名需要手动指定或从API结果中提取
-                // 这里我们暂时使用一个placeholder，或者尝试从本地已存在的文件列表中查找
-                let mut file_name = format!("downloaded_file_{}", id_to_download); // 默认文件名
-
-                // 尝试从本地文件列表中查找文件名
-                if let Some(entry) = app_state.files.iter().find(|f| f.id == id_to_download) {
-                    file_name = entry.name.clone();
-                }
+                // 本地列表中有记录时使用记录的文件名和密钥，否则由下载结果中的元数据或文件内容决定
+                let local_entry = app_state.files.iter().find(|f| f.id == id_to_download);
+                let file_name = local_entry.map(|entry| entry.name.clone());
+                let file_key = local_entry.and_then(|entry| entry.file_key.clone());
 
-                app_state.status_message = format!(
-                    "正在下载文件 (ID: {}) 到 {}...",
-                    id_to_download,
-                    download_path.to_string_lossy()
+                app_state.status_message = t!(
+                    "downloading-id-to",
+                    id = id_to_download.as_str(),
+                    path = download_path.to_string_lossy().into_owned()
                 );
-                let walrus_api = WalrusApi::default();
+                let walrus_api = WalrusApi::new(&app_state.settings.network);
+                let policy = app_state.settings.download.conflict_policy;
+                let subject = file_name.clone().unwrap_or_else(|| id_to_download.clone());
                 Command::perform(
-                    async move {
+                    timed(subject, async move {
                         walrus_api
-                            .download_file(id_to_download.clone(), file_name, download_path)
+                            .download_file(
+                                id_to_download.clone(),
+                                file_name,
+                                download_path,
+                                policy,
+                                file_key,
+                            )
                             .await
-                    },
+                    }),
                     Message::DownloadComplete,
                 )
             } else {
-                app_state.status_message = "未选择下载路径。".into();
+                app_state.status_message = t!("no-download-path");
                 Command::none()
             }
         }
+        Message::AddBlobNameChanged(value) => {
+            app_state.add_blob_name = value;
+            Command::none()
+        }
+        Message::AddBlobTagsChanged(value) => {
+            app_state.add_blob_tags = value;
+            Command::none()
+        }
+        Message::AddExistingBlobPressed => {
+            let id = match validate_blob_id(&app_state.download_id_input) {
+                Ok(id) => id,
+                Err(e) => {
+                    app_state.status_message = e;
+                    return Command::none();
+                }
+            };
+            if app_state.files.iter().any(|f| f.id == id) {
+                app_state.status_message = t!("id-already-listed", id = id.as_str());
+                return Command::none();
+            }
+            let name = match app_state.add_blob_name.trim() {
+                "" => format!("blob_{}", id),
+                name => name.to_string(),
+            };
+            let mut entry = FileEntry::new(id.clone(), name);
+            entry.tags = parse_tags(&app_state.add_blob_tags);
+
+            app_state.status_message = t!("probing-blob", id = id.as_str());
+            app_state.probes_in_progress += 1;
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            Command::perform(
+                timed(id, async move {
+                    let probe = walrus_api.probe_blob(&entry.id).await?;
+                    entry.size = probe.size;
+                    entry.mime = probe.mime;
+                    Ok::<_, String>(entry)
+                }),
+                Message::ExistingBlobProbed,
+            )
+        }
+        Message::ExistingBlobProbed(timed) => {
+            app_state.probes_in_progress = app_state.probes_in_progress.saturating_sub(1);
+            // 按 ID 添加已有 blob 相当于导入一条记录，成功和失败都记为导入
+            let record = match timed.result {
+                Ok(entry) if app_state.files.iter().any(|f| f.id == entry.id) => {
+                    app_state.status_message = t!("id-already-listed", id = entry.id.as_str());
+                    ActivityRecord::new(
+                        ActivityKind::Import,
+                        timed.subject,
+                        false,
+                        app_state.status_message.as_str(),
+                    )
+                }
+                Ok(entry) => {
+                    app_state.status_message = match entry.size {
+                        Some(size) => t!(
+                            "blob-added-with-size",
+                            name = entry.name.as_str(),
+                            size = size
+                        ),
+                        None => t!("blob-added", name = entry.name.as_str()),
+                    };
+                    let record = ActivityRecord::new(
+                        ActivityKind::Import,
+                        entry.name.as_str(),
+                        true,
+                        &entry.id,
+                    )
+                    .with_bytes(entry.size);
+                    app_state.files.push(entry);
+                    save_file_entries(&app_state.workspace, &app_state.files);
+                    app_state.download_id_input.clear();
+                    app_state.add_blob_name.clear();
+                    app_state.add_blob_tags.clear();
+                    record
+                }
+                Err(e) => {
+                    app_state.status_message = t!("add-blob-failed", error = e.as_str());
+                    ActivityRecord::new(ActivityKind::Import, timed.subject, false, e)
+                }
+            };
+            log_activity(app_state, record.with_duration(timed.elapsed));
+            Command::none()
+        }
+        Message::ToggleGridView => {
+            app_state.settings.display.grid_view = !app_state.settings.display.grid_view;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::ThumbnailLoaded(id, result) => {
+            let status = match result {
+                Ok(path) => ThumbnailStatus::Ready(iced::widget::image::Handle::from_path(path)),
+                Err(e) => {
+                    eprintln!("获取缩略图失败 (ID: {}): {}", id, e);
+                    ThumbnailStatus::Unavailable
+                }
+            };
+            app_state.thumbnails.insert(id, status);
+            Command::none()
+        }
+        Message::PreviewButtonPressed(id) => {
+            let Some(entry) = app_state.files.iter().find(|f| f.id == id) else {
+                app_state.status_message = t!("file-id-not-found", id = id.as_str());
+                return Command::none();
+            };
+            let name = entry.name.clone();
+            let file_key = entry.file_key.clone();
+            app_state.preview = Some(PreviewPane::loading(id.clone(), name.clone()));
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            let fetch_id = id.clone();
+            Command::perform(
+                async move {
+                    let (data, truncated) = walrus_api
+                        .fetch_blob_prefix(&fetch_id, PREVIEW_SIZE_LIMIT)
+                        .await?;
+                    // 加密文件只有完整下载后才能解密
+                    if truncated && file_key.is_some() {
+                        return Err(t!("encrypted-preview-too-large"));
+                    }
+                    let data = decrypt_if_needed(data, file_key.as_deref())?;
+                    prepare_preview(Some(name), data, truncated)
+                },
+                move |result| Message::PreviewLoaded(id, result),
+            )
+        }
+        Message::PreviewLoaded(id, result) => {
+            // 忽略已经关闭或切换到其他文件的预览
+            if let Some(preview) = app_state.preview.as_mut().filter(|p| p.id == id) {
+                match result {
+                    Ok(data) => preview.show(data),
+                    Err(e) => preview.body = PreviewBody::Error(e),
+                }
+            }
+            Command::none()
+        }
+        Message::PreviewEditorAction(action) => {
+            // 预览只读，只处理光标移动和选择
+            if let Some(PreviewBody::Text { content, .. }) =
+                app_state.preview.as_mut().map(|p| &mut p.body)
+                && !action.is_edit()
+            {
+                content.perform(action);
+            }
+            Command::none()
+        }
+        Message::ClosePreview => {
+            app_state.preview = None;
+            Command::none()
+        }
+        Message::ConflictPolicyChanged(policy) => {
+            app_state.settings.download.conflict_policy = policy;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::ResolveDownloadConflict(policy) => {
+            if app_state.download_conflicts.is_empty() {
+                return Command::none();
+            }
+            // 记住选择时设为默认处理方式，并用它处理其余等待决定的冲突
+            let conflicts: Vec<_> = if app_state.remember_conflict_choice {
+                app_state.remember_conflict_choice = false;
+                app_state.settings.download.conflict_policy = policy;
+                save_settings(&app_state.workspace, &app_state.settings);
+                app_state.download_conflicts.drain(..).collect()
+            } else {
+                vec![app_state.download_conflicts.remove(0)]
+            };
+            for conflict in conflicts {
+                app_state.status_message =
+                    match save_download(conflict.path, conflict.content, policy) {
+                        Ok(outcome) => outcome.to_string(),
+                        Err(e) => t!("download-failed", error = e),
+                    };
+            }
+            Command::none()
+        }
+        Message::RememberConflictChoiceToggled(remember) => {
+            app_state.remember_conflict_choice = remember;
+            Command::none()
+        }
+        Message::WrapMetadataToggled(enabled) => {
+            app_state.settings.upload.wrap_metadata = enabled;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::EncryptUploadsToggled(enabled) => {
+            app_state.settings.upload.encrypt = enabled;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::DeletableUploadsToggled(enabled) => {
+            app_state.settings.upload.deletable = enabled;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::WalrusCliChanged(path) => {
+            app_state.settings.chain.walrus_cli = path;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::WalrusConfigChanged(path) => {
+            app_state.settings.chain.walrus_config = path;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::SuiAddressChanged(input) => {
+            // 留空表示不指定地址，blob 对象由发布器持有
+            let address = if input.trim().is_empty() {
+                Some(String::new())
+            } else {
+                validate_sui_address(&input).ok()
+            };
+            if let Some(address) = address {
+                app_state.settings.chain.sui_address = address;
+                save_settings(&app_state.workspace, &app_state.settings);
+            }
+            app_state.sui_address_input = input;
+            Command::none()
+        }
+        Message::KeystorePathChanged(path) => {
+            app_state.settings.chain.keystore_path = path;
+            app_state.keystore_addresses.clear();
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::RefreshPricePressed => fetch_prices(app_state),
+        Message::PriceInfoLoaded(result) => {
+            app_state.price_status = match result {
+                Ok(info) => PriceStatus::Ready(info),
+                Err(e) => PriceStatus::Failed(e),
+            };
+            Command::none()
+        }
+        Message::LoadKeystorePressed => {
+            let path = app_state.settings.chain.keystore_path.trim();
+            match read_keystore(Path::new(path)) {
+                Ok(addresses) => {
+                    app_state.status_message = t!("keystore-loaded", count = addresses.len());
+                    app_state.keystore_addresses = addresses;
+                }
+                Err(e) => {
+                    app_state.keystore_addresses.clear();
+                    app_state.status_message = e;
+                }
+            }
+            Command::none()
+        }
+        Message::DeleteFromWalrusPressed(id) => {
+            let own_address = &app_state.settings.chain.sui_address;
+            match app_state.files.iter().find(|f| f.id == id) {
+                Some(entry) if can_delete(entry, own_address) => {
+                    app_state.pending_confirmation = Some(ConfirmAction::DeleteFromWalrus(id));
+                }
+                Some(entry) if entry.deletable => {
+                    app_state.status_message = t!("blob-not-owned", name = entry.name.as_str());
+                }
+                Some(entry) => {
+                    app_state.status_message = t!("blob-not-deletable", name = entry.name.as_str());
+                }
+                None => app_state.status_message = t!("file-id-not-found", id = id.as_str()),
+            }
+            Command::none()
+        }
+        Message::ThemeSelected(name) => {
+            app_state.preferences.theme = name;
+            save_preferences(&app_state.preferences);
+            apply_theme(app_state);
+            Command::none()
+        }
+        Message::LanguageSelected(language) => {
+            app_state.preferences.language = language;
+            save_preferences(&app_state.preferences);
+            set_language(language);
+            Command::none()
+        }
         Message::UploadProgress(progress) => {
             app_state.upload_progress = progress;
             Command::none()
         }
-        Message::UploadComplete(result) => {
+        Message::UploadComplete(timed) => {
             app_state.upload_progress = 0.0;
-            match result {
+            let record = match timed.result {
                 Ok(entry) => {
                     app_state.files.push(entry.clone());
-                    save_file_entries(&app_state.files);
-                    app_state.status_message = format!("上传成功，ID: {}", entry.id);
-                    app_state.upload_file_path = String::new();
+                    save_file_entries(&app_state.workspace, &app_state.files);
+                    app_state.spend.record(entry.cost.unwrap_or(0));
+                    save_spend(&app_state.workspace, &app_state.spend);
+                    app_state.status_message = t!("upload-succeeded", id = entry.id.as_str());
+                    ActivityRecord::new(ActivityKind::Upload, entry.name, true, entry.id)
+                        .with_bytes(entry.size)
                 }
                 Err(e) => {
-                    app_state.status_message = format!("上传失败: {}", e);
+                    app_state.status_message = t!("upload-failed", error = e.as_str());
+                    ActivityRecord::new(ActivityKind::Upload, timed.subject, false, e)
                 }
+            };
+            log_activity(app_state, record.with_duration(timed.elapsed));
+            app_state.upload_in_progress = false;
+            if !app_state.upload_queue.is_empty() {
+                app_state.status_message = t!(
+                    "upload-queue-remaining",
+                    status = app_state.status_message.as_str(),
+                    count = app_state.upload_queue.len()
+                );
             }
-            Command::none()
+            start_next_upload(app_state)
         }
-        Message::DownloadComplete(result) => {
-            match result {
-                Ok(path) => {
-                    app_state.status_message = format!("下载成功到: {}", path);
+        Message::DownloadComplete(timed) => {
+            let record = match timed.result {
+                Ok(DownloadOutcome::Conflict(conflict)) => {
+                    app_state.status_message = t!(
+                        "download-conflict",
+                        path = conflict.path.display().to_string()
+                    );
+                    let bytes = conflict.content.len() as u64;
+                    app_state.download_conflicts.push(conflict);
+                    ActivityRecord::new(
+                        ActivityKind::Download,
+                        timed.subject,
+                        true,
+                        app_state.status_message.as_str(),
+                    )
+                    .with_bytes(Some(bytes))
+                }
+                Ok(outcome) => {
+                    app_state.status_message = outcome.to_string();
+                    let bytes = match &outcome {
+                        DownloadOutcome::Saved(path) => fs::metadata(path).ok().map(|m| m.len()),
+                        _ => None,
+                    };
+                    ActivityRecord::new(
+                        ActivityKind::Download,
+                        timed.subject,
+                        true,
+                        app_state.status_message.as_str(),
+                    )
+                    .with_bytes(bytes)
                 }
                 Err(e) => {
-                    app_state.status_message = format!("下载失败: {}", e);
+                    app_state.status_message = t!("download-failed", error = e.as_str());
+                    ActivityRecord::new(ActivityKind::Download, timed.subject, false, e)
                 }
-            }
+            };
+            log_activity(app_state, record.with_duration(timed.elapsed));
             Command::none()
         }
-        Message::DeleteComplete(result) => {
-            match result {
+        Message::DeleteComplete(workspace, timed) => {
+            // 删除期间可能已切换工作区，只修改发起删除的工作区的记录
+            let active = workspace == app_state.workspace;
+            let record = match timed.result {
                 Ok(deleted_id) => {
-                    app_state.files.retain(|f| f.id != deleted_id);
-                    save_file_entries(&app_state.files);
-                    app_state.status_message = format!("文件已删除，ID: {}", deleted_id);
+                    // blob 已不存在，记录直接移除而不放入回收站
+                    let mut files = if active {
+                        std::mem::take(&mut app_state.files)
+                    } else {
+                        load_file_entries(&workspace)
+                    };
+                    let bytes = files
+                        .iter()
+                        .find(|f| f.id == deleted_id)
+                        .and_then(|f| f.size);
+                    files.retain(|f| f.id != deleted_id);
+                    save_file_entries(&workspace, &files);
+                    if active {
+                        app_state.files = files;
+                        app_state.selected_files.remove(&deleted_id);
+                    }
+                    app_state.status_message = t!("file-deleted", id = deleted_id.as_str());
+                    ActivityRecord::new(ActivityKind::Delete, timed.subject, true, deleted_id)
+                        .with_bytes(bytes)
                 }
                 Err(e) => {
-                    app_state.status_message = format!("删除失败: {}", e);
+                    app_state.status_message = t!("delete-failed", error = e.as_str());
+                    ActivityRecord::new(ActivityKind::Delete, timed.subject, false, e)
                 }
+            };
+            let record = record.with_duration(timed.elapsed);
+            if active {
+                log_activity(app_state, record);
+            } else {
+                let mut log = load_activity(&workspace);
+                activity::append(&mut log, record);
+                save_activity(&workspace, &log);
             }
             Command::none()
         }
@@ -237,7 +730,7 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
             Command::none()
         }
         Message::CopyIdToClipboard(id) => {
-            app_state.status_message = format!("文件 ID 已复制到剪贴板: {}", id);
+            app_state.status_message = t!("id-copied", id = id.as_str());
             Command::perform(
                 async move {
                     let mut clipboard = arboard::Clipboard::new().unwrap();
@@ -247,93 +740,595 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
                 |_| Message::NoOp,
             )
         }
+        Message::ShareButtonPressed(id) => {
+            let Some(entry) = app_state.files.iter().find(|f| f.id == id) else {
+                app_state.status_message = t!("file-id-not-found", id = id.as_str());
+                return Command::none();
+            };
+            app_state.share_dialog = Some(ShareDialog {
+                id,
+                name: entry.name.clone(),
+                include_name: true,
+                encrypted: entry.file_key.is_some(),
+                url: String::new(),
+                qr: None,
+            });
+            refresh_share_link(app_state);
+            Command::none()
+        }
+        Message::ShareIncludeNameToggled(include_name) => {
+            if let Some(dialog) = app_state.share_dialog.as_mut() {
+                dialog.include_name = include_name;
+            }
+            refresh_share_link(app_state);
+            Command::none()
+        }
+        Message::CopyShareLink => {
+            let Some(url) = app_state
+                .share_dialog
+                .as_ref()
+                .filter(|d| !d.url.is_empty())
+                .map(|d| d.url.clone())
+            else {
+                return Command::none();
+            };
+            app_state.status_message = t!("share-link-copied", url = url.as_str());
+            Command::perform(
+                async move {
+                    let mut clipboard = arboard::Clipboard::new().unwrap();
+                    clipboard.set_text(url).unwrap();
+                    async_std::task::sleep(std::time::Duration::from_millis(100)).await;
+                },
+                |_| Message::NoOp,
+            )
+        }
+        Message::CloseShareDialog => {
+            app_state.share_dialog = None;
+            Command::none()
+        }
         Message::NoOp => Command::none(),
         Message::SearchInputChanged(input) => {
             app_state.search_input = input;
             Command::none()
         }
+        Message::FocusSearch => text_input::focus(search_input_id()),
+        Message::SelectAllVisibleToggled(checked) => {
+            let visible = visible_ids(&app_state.files, &app_state.search_input);
+            selection::set_visible(&mut app_state.selected_files, &visible, checked);
+            Command::none()
+        }
+        Message::InvertSelection => {
+            let visible = visible_ids(&app_state.files, &app_state.search_input);
+            selection::invert_visible(&mut app_state.selected_files, &visible);
+            Command::none()
+        }
+        Message::ModifiersChanged(modifiers) => {
+            app_state.keyboard_modifiers = modifiers;
+            Command::none()
+        }
+        Message::ToggleCommandPalette => {
+            if app_state.command_palette.take().is_some() {
+                return Command::none();
+            }
+            app_state.command_palette = Some(String::new());
+            text_input::focus(palette_input_id())
+        }
+        Message::CommandPaletteInputChanged(query) => {
+            app_state.command_palette = Some(query);
+            Command::none()
+        }
+        Message::CommandPaletteSubmit => {
+            let query = app_state.command_palette.clone().unwrap_or_default();
+            match matching_commands(&query).first() {
+                Some(command) => handle_message(app_state, Message::RunCommand(*command)),
+                None => Command::none(),
+            }
+        }
+        Message::RunCommand(command) => {
+            app_state.command_palette = None;
+            handle_message(app_state, command.message())
+        }
+        Message::CloseCommandPalette => {
+            app_state.command_palette = None;
+            Command::none()
+        }
+        Message::Shortcut(command) => {
+            // 对话框和面板会替换文件列表，快捷键不应作用于看不见的列表
+            if overlay_open(app_state) {
+                return Command::none();
+            }
+            handle_message(app_state, command.message())
+        }
         Message::FileSelectedForBatch(id, is_selected) => {
-            if is_selected {
-                app_state.selected_files.insert(id);
-            } else {
-                app_state.selected_files.remove(&id);
+            // 按住 Shift 点击时选择从上一次点击的文件到当前文件之间的范围
+            match &app_state.selection_anchor {
+                Some(anchor) if app_state.keyboard_modifiers.shift() => {
+                    let visible = visible_ids(&app_state.files, &app_state.search_input);
+                    selection::select_range(
+                        &mut app_state.selected_files,
+                        &visible,
+                        anchor,
+                        &id,
+                        is_selected,
+                    );
+                }
+                _ => {
+                    if is_selected {
+                        app_state.selected_files.insert(id.clone());
+                    } else {
+                        app_state.selected_files.remove(&id);
+                    }
+                    app_state.selection_anchor = Some(id);
+                }
             }
             Command::none()
         }
         Message::BatchDeleteButtonPressed => {
-            let ids_to_delete: Vec<String> = app_state.selected_files.drain().collect(); // 清空并获取所有选中的ID
+            // 按列表顺序排列，确认对话框中显示的文件名与列表一致
+            let ids_to_delete: Vec<String> = app_state
+                .files
+                .iter()
+                .filter(|f| app_state.selected_files.contains(&f.id))
+                .map(|f| f.id.clone())
+                .collect();
             if ids_to_delete.is_empty() {
-                app_state.status_message = "没有选择任何文件进行批量删除。".into();
+                app_state.status_message = t!("batch-delete-nothing-selected");
                 return Command::none();
             }
-
-            for id in &ids_to_delete {
-                // 迭代引用而不是移动所有权
-                app_state.files.retain(|f| f.id != *id); // 解引用 id
+            // 不替换正在等待确认的其他操作
+            if app_state.pending_confirmation.is_some() {
+                return Command::none();
             }
-            save_file_entries(&app_state.files);
-            app_state.status_message = format!("已批量删除 {} 个文件记录。", ids_to_delete.len());
+            app_state.pending_confirmation = Some(ConfirmAction::DeleteEntries(ids_to_delete));
             Command::none()
         }
-        Message::BatchDownloadButtonPressed => {
-            if app_state.selected_files.is_empty() {
-                app_state.status_message = "没有选择任何文件进行批量下载。".into();
+        Message::ConfirmPendingAction => {
+            let Some(action) = app_state.pending_confirmation.take() else {
                 return Command::none();
+            };
+            match action {
+                ConfirmAction::DeleteEntries(ids) => {
+                    let count = move_to_trash(
+                        &mut app_state.files,
+                        &mut app_state.trash,
+                        &ids,
+                        &trash::now(),
+                    );
+                    for id in &ids {
+                        app_state.selected_files.remove(id);
+                    }
+                    save_file_entries(&app_state.workspace, &app_state.files);
+                    save_trash(&app_state.workspace, &app_state.trash);
+                    app_state.status_message = t!("moved-to-trash", count = count);
+                    let subject = t!("activity-entries-subject", count = count);
+                    let detail = app_state.status_message.clone();
+                    log_activity(
+                        app_state,
+                        ActivityRecord::new(ActivityKind::Delete, subject, true, detail),
+                    );
+                }
+                ConfirmAction::DeleteFromWalrus(id) => {
+                    let Some(entry) = app_state.files.iter().find(|f| f.id == id).cloned() else {
+                        return Command::none();
+                    };
+                    let deleter = WalrusCli::new(&app_state.settings.chain);
+                    let own_address = app_state.settings.chain.sui_address.clone();
+                    let workspace = app_state.workspace.clone();
+                    app_state.status_message =
+                        t!("deleting-from-walrus", name = entry.name.as_str());
+                    return Command::perform(
+                        timed(entry.name.clone(), async move {
+                            delete_from_walrus(&deleter, &entry, &own_address).await
+                        }),
+                        move |timed| Message::DeleteComplete(workspace, timed),
+                    );
+                }
+                ConfirmAction::ReplaceImport { .. } => apply_pending_import(app_state),
+                ConfirmAction::PurgeTrash(ids) => {
+                    app_state.trash.retain(|item| !ids.contains(&item.entry.id));
+                    save_trash(&app_state.workspace, &app_state.trash);
+                    app_state.status_message = t!("trash-purged", count = ids.len());
+                }
+                ConfirmAction::ShortenRetention { days, .. } => set_retention_days(app_state, days),
+                ConfirmAction::DeleteWorkspace(name) => match delete_workspace(&name) {
+                    Ok(()) => {
+                        app_state.workspaces = list_workspaces();
+                        if app_state.transfer_target.as_ref() == Some(&name) {
+                            app_state.transfer_target = None;
+                        }
+                        app_state.status_message = t!("workspace-deleted", name = name.as_str());
+                    }
+                    Err(e) => app_state.status_message = e,
+                },
             }
-            Command::perform(async {}, |_| Message::TriggerBatchDownloadSelection)
+            Command::none()
         }
-        Message::TriggerBatchDownloadSelection => Command::perform(
+        Message::CancelPendingAction => {
+            if matches!(
+                app_state.pending_confirmation,
+                Some(ConfirmAction::ShortenRetention { .. })
+            ) {
+                app_state.trash_retention_input =
+                    app_state.settings.trash.retention_days.to_string();
+            }
+            app_state.pending_confirmation = None;
+            Command::none()
+        }
+        Message::ToggleActivityPanel => {
+            app_state.show_activity_panel = !app_state.show_activity_panel;
+            Command::none()
+        }
+        Message::ActivityKindFilterChanged(filter) => {
+            app_state.activity_kind_filter = filter;
+            Command::none()
+        }
+        Message::ActivityResultFilterChanged(filter) => {
+            app_state.activity_result_filter = filter;
+            Command::none()
+        }
+        Message::TriggerExportActivity => Command::perform(
             async {
                 let initial_directory = UserDirs::new()
-                    .and_then(|user_dirs| user_dirs.download_dir().map(|path| path.to_path_buf()))
+                    .and_then(|user_dirs| user_dirs.document_dir().map(|path| path.to_path_buf()))
                     .unwrap_or_else(|| PathBuf::from("."));
 
                 let pick_result = AsyncFileDialog::new()
                     .set_directory(initial_directory)
-                    .pick_folder()
+                    .set_file_name("walrus_store_activity.csv")
+                    .save_file()
                     .await;
-                Message::BatchDownloadLocationSelected(
+                Message::ExportActivitySelected(
                     pick_result.map(|handle| handle.path().to_path_buf()),
                 )
             },
             |msg| msg,
         ),
-        Message::BatchDownloadLocationSelected(path_opt) => {
-            if let Some(download_path) = path_opt {
-                let ids_to_download: Vec<String> = app_state.selected_files.drain().collect(); // 清空并获取所有选中的ID
-                if ids_to_download.is_empty() {
-                    app_state.status_message = "没有选择任何文件进行批量下载。".into();
-                    return Command::none();
-                }
-
-                let mut commands = Vec::new();
+        Message::ExportActivitySelected(path_opt) => {
+            let Some(path) = path_opt else {
+                app_state.status_message = t!("no-export-path");
+                return Command::none();
+            };
+            // 只导出当前筛选条件下的记录
+            let records = activity::filter(
+                &app_state.activity,
+                app_state.activity_kind_filter,
+                app_state.activity_result_filter,
+            );
+            app_state.status_message = match fs::write(&path, activity::to_csv(&records)) {
+                Ok(()) => t!(
+                    "activity-exported",
+                    count = records.len(),
+                    path = path.to_string_lossy().into_owned()
+                ),
+                Err(e) => t!("export-activity-failed", error = e.to_string()),
+            };
+            Command::none()
+        }
+        Message::ToggleTrashPanel => {
+            app_state.show_trash_panel = !app_state.show_trash_panel;
+            // 未按回车应用的保留天数不保留
+            app_state.trash_retention_input = app_state.settings.trash.retention_days.to_string();
+            Command::none()
+        }
+        Message::RestoreFromTrash(id) => {
+            match restore_from_trash(&mut app_state.files, &mut app_state.trash, &id) {
+                Ok(entry) => {
+                    save_file_entries(&app_state.workspace, &app_state.files);
+                    save_trash(&app_state.workspace, &app_state.trash);
+                    app_state.status_message =
+                        t!("restored-from-trash", name = entry.name.as_str());
+                }
+                Err(e) => app_state.status_message = e,
+            }
+            Command::none()
+        }
+        Message::PurgeFromTrashPressed(id) => {
+            app_state.pending_confirmation = Some(ConfirmAction::PurgeTrash(vec![id]));
+            Command::none()
+        }
+        Message::EmptyTrashPressed => {
+            if app_state.trash.is_empty() {
+                app_state.status_message = t!("trash-empty");
+            } else {
+                let ids = app_state.trash.iter().map(|t| t.entry.id.clone()).collect();
+                app_state.pending_confirmation = Some(ConfirmAction::PurgeTrash(ids));
+            }
+            Command::none()
+        }
+        Message::TrashRetentionChanged(input) => {
+            app_state.trash_retention_input = input;
+            Command::none()
+        }
+        Message::TrashRetentionSubmitted => {
+            let Ok(days) = app_state.trash_retention_input.trim().parse::<u32>() else {
+                app_state.status_message = t!(
+                    "invalid-retention-days",
+                    input = app_state.trash_retention_input.as_str()
+                );
+                app_state.trash_retention_input =
+                    app_state.settings.trash.retention_days.to_string();
+                return Command::none();
+            };
+            // 缩短保留期会立即清除已过期的记录，需要先确认
+            let purged = count_expired(&app_state.trash, days, &trash::now());
+            if purged > 0 {
+                app_state.pending_confirmation =
+                    Some(ConfirmAction::ShortenRetention { days, purged });
+            } else {
+                set_retention_days(app_state, days);
+            }
+            Command::none()
+        }
+        Message::BatchDownloadButtonPressed => {
+            if app_state.selected_files.is_empty() {
+                app_state.status_message = t!("batch-download-nothing-selected");
+                return Command::none();
+            }
+            Command::perform(async {}, |_| Message::TriggerBatchDownloadSelection)
+        }
+        Message::TriggerBatchDownloadSelection => Command::perform(
+            async {
+                let initial_directory = UserDirs::new()
+                    .and_then(|user_dirs| user_dirs.download_dir().map(|path| path.to_path_buf()))
+                    .unwrap_or_else(|| PathBuf::from("."));
+
+                let pick_result = AsyncFileDialog::new()
+                    .set_directory(initial_directory)
+                    .pick_folder()
+                    .await;
+                Message::BatchDownloadLocationSelected(
+                    pick_result.map(|handle| handle.path().to_path_buf()),
+                )
+            },
+            |msg| msg,
+        ),
+        Message::BatchDownloadLocationSelected(path_opt) => {
+            if let Some(download_path) = path_opt {
+                let ids_to_download: Vec<String> = app_state.selected_files.drain().collect(); // 清空并获取所有选中的ID
+                if ids_to_download.is_empty() {
+                    app_state.status_message = t!("batch-download-nothing-selected");
+                    return Command::none();
+                }
+
+                let policy = app_state.settings.download.conflict_policy;
+                let mut commands = Vec::new();
                 for id in ids_to_download {
                     if let Some(entry) = app_state.files.iter().find(|f| f.id == id).cloned() {
-                        let walrus_api = WalrusApi::default();
+                        let walrus_api = WalrusApi::new(&app_state.settings.network);
                         let download_path_clone = download_path.clone();
                         commands.push(Command::perform(
-                            async move {
+                            timed(entry.name.clone(), async move {
                                 walrus_api
                                     .download_file(
                                         entry.id.clone(),
-                                        entry.name.clone(),
+                                        Some(entry.name.clone()),
                                         download_path_clone,
+                                        policy,
+                                        entry.file_key.clone(),
                                     )
                                     .await
-                            },
+                            }),
                             Message::DownloadComplete,
                         ));
                     } else {
-                        app_state.status_message = format!("找不到文件 ID: {}", id);
+                        app_state.status_message = t!("file-id-not-found", id = id.as_str());
                     }
                 }
-                app_state.status_message = format!("正在批量下载 {} 个文件...", commands.len());
+                app_state.status_message = t!("batch-download-started", count = commands.len());
                 Command::batch(commands)
             } else {
-                app_state.status_message = "未选择批量下载路径。".into();
+                app_state.status_message = t!("no-batch-download-path");
                 Command::none()
             }
         }
+        Message::ShareSelectionPressed => {
+            let entries: Vec<FileEntry> = app_state
+                .files
+                .iter()
+                .filter(|f| app_state.selected_files.contains(&f.id))
+                .cloned()
+                .collect();
+            if entries.is_empty() {
+                app_state.status_message = t!("select-files-to-share");
+                return Command::none();
+            }
+            // 加密分享时自动把自己加入接收人，方便之后在其他设备上打开
+            let mut recipients: Vec<String> = app_state.share_recipients.iter().cloned().collect();
+            if let Some(identity) = &app_state.identity
+                && !recipients.is_empty()
+                && !recipients.contains(&identity.public_key)
+            {
+                recipients.push(identity.public_key.clone());
+            }
+            recipients.sort();
+            app_state.status_message = t!("publishing-bundle", count = entries.len());
+            let bundle = ShareBundle::new(&entries);
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            let epochs = app_state.settings.upload.snapshot_epochs;
+            let subject = t!("activity-bundle-subject", count = entries.len());
+            Command::perform(
+                timed(subject, async move {
+                    publish_bundle(&walrus_api, &bundle, &recipients, epochs).await
+                }),
+                Message::BundlePublished,
+            )
+        }
+        Message::BundlePublished(timed) => match timed.result {
+            Ok(id) => {
+                app_state.status_message = t!("bundle-published", id = id.as_str());
+                let record = ActivityRecord::new(ActivityKind::Upload, timed.subject, true, &id)
+                    .with_duration(timed.elapsed);
+                log_activity(app_state, record);
+                Command::perform(
+                    async move {
+                        let mut clipboard = arboard::Clipboard::new().unwrap();
+                        clipboard.set_text(id).unwrap();
+                        async_std::task::sleep(std::time::Duration::from_millis(100)).await;
+                    },
+                    |_| Message::NoOp,
+                )
+            }
+            Err(e) => {
+                app_state.status_message = t!("publish-bundle-failed", error = e.as_str());
+                let record = ActivityRecord::new(ActivityKind::Upload, timed.subject, false, e)
+                    .with_duration(timed.elapsed);
+                log_activity(app_state, record);
+                Command::none()
+            }
+        },
+        Message::OpenBundlePressed => {
+            let id = match validate_blob_id(&app_state.download_id_input) {
+                Ok(id) => id,
+                Err(e) => {
+                    app_state.status_message = e;
+                    return Command::none();
+                }
+            };
+            app_state.status_message = t!("loading-bundle", id = id.as_str());
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            let identity = app_state.identity.clone();
+            let fetch_id = id.clone();
+            Command::perform(
+                async move { fetch_bundle(&walrus_api, fetch_id, identity).await },
+                move |result| Message::BundleLoaded(id, result),
+            )
+        }
+        Message::BundleLoaded(id, result) => {
+            match result {
+                Ok(bundle) => {
+                    app_state.status_message = t!("bundle-loaded", count = bundle.entries.len());
+                    app_state.open_bundle = Some((id, bundle));
+                }
+                Err(e) => {
+                    app_state.status_message = t!("open-bundle-failed", error = e.as_str());
+                    log_activity(
+                        app_state,
+                        ActivityRecord::new(ActivityKind::Download, id, false, e),
+                    );
+                }
+            }
+            Command::none()
+        }
+        Message::ImportBundlePressed => {
+            if let Some((id, bundle)) = app_state.open_bundle.take() {
+                let incoming = bundle
+                    .entries
+                    .iter()
+                    .map(BundleEntry::to_file_entry)
+                    .collect();
+                // 分享包只包含部分文件，默认合并而不是替换本地列表
+                stage_import(
+                    app_state,
+                    t!("import-source-bundle", id = id.as_str()),
+                    incoming,
+                    ImportMode::Merge,
+                );
+            }
+            Command::none()
+        }
+        Message::TriggerBundleDownloadSelection => Command::perform(
+            async {
+                let initial_directory = UserDirs::new()
+                    .and_then(|user_dirs| user_dirs.download_dir().map(|path| path.to_path_buf()))
+                    .unwrap_or_else(|| PathBuf::from("."));
+
+                let pick_result = AsyncFileDialog::new()
+                    .set_directory(initial_directory)
+                    .pick_folder()
+                    .await;
+                Message::BundleDownloadLocationSelected(
+                    pick_result.map(|handle| handle.path().to_path_buf()),
+                )
+            },
+            |msg| msg,
+        ),
+        Message::BundleDownloadLocationSelected(path_opt) => {
+            let (Some(download_path), Some((_, bundle))) = (path_opt, &app_state.open_bundle)
+            else {
+                app_state.status_message = t!("no-download-path");
+                return Command::none();
+            };
+            let policy = app_state.settings.download.conflict_policy;
+            let commands: Vec<_> = bundle
+                .entries
+                .iter()
+                .map(|entry| {
+                    let walrus_api = WalrusApi::new(&app_state.settings.network);
+                    let (id, name) = (entry.id.clone(), entry.name.clone());
+                    let file_key = entry.file_key.clone();
+                    let download_path = download_path.clone();
+                    Command::perform(
+                        timed(name.clone(), async move {
+                            walrus_api
+                                .download_file(id, Some(name), download_path, policy, file_key)
+                                .await
+                        }),
+                        Message::DownloadComplete,
+                    )
+                })
+                .collect();
+            app_state.status_message = t!("bundle-download-started", count = commands.len());
+            app_state.open_bundle = None;
+            Command::batch(commands)
+        }
+        Message::CloseBundle => {
+            app_state.open_bundle = None;
+            Command::none()
+        }
+        Message::ToggleIdentityPanel => {
+            app_state.show_identity_panel = !app_state.show_identity_panel;
+            Command::none()
+        }
+        Message::CopyPublicKey => {
+            let Some(identity) = &app_state.identity else {
+                return Command::none();
+            };
+            let public_key = identity.public_key.clone();
+            app_state.status_message = t!("public-key-copied");
+            Command::perform(
+                async move {
+                    let mut clipboard = arboard::Clipboard::new().unwrap();
+                    clipboard.set_text(public_key).unwrap();
+                    async_std::task::sleep(std::time::Duration::from_millis(100)).await;
+                },
+                |_| Message::NoOp,
+            )
+        }
+        Message::NewContactNameChanged(name) => {
+            app_state.new_contact_name = name;
+            Command::none()
+        }
+        Message::NewContactKeyChanged(key) => {
+            app_state.new_contact_key = key;
+            Command::none()
+        }
+        Message::AddContactPressed => {
+            match validate_contact(&app_state.new_contact_name, &app_state.new_contact_key) {
+                Ok(contact) => {
+                    app_state.status_message = t!("contact-added", name = contact.name.as_str());
+                    app_state.contacts.push(contact);
+                    save_contacts(&app_state.contacts);
+                    app_state.new_contact_name.clear();
+                    app_state.new_contact_key.clear();
+                }
+                Err(e) => app_state.status_message = e,
+            }
+            Command::none()
+        }
+        Message::RemoveContact(public_key) => {
+            app_state.contacts.retain(|c| c.public_key != public_key);
+            app_state.share_recipients.remove(&public_key);
+            save_contacts(&app_state.contacts);
+            Command::none()
+        }
+        Message::ShareRecipientToggled(public_key, selected) => {
+            if selected {
+                app_state.share_recipients.insert(public_key);
+            } else {
+                app_state.share_recipients.remove(&public_key);
+            }
+            Command::none()
+        }
         Message::TriggerExportConfig => Command::perform(
             async {
                 let initial_directory = UserDirs::new()
@@ -351,18 +1346,40 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
         ),
         Message::ExportConfigSelected(path_opt) => {
             if let Some(path) = path_opt {
-                match serde_json::to_string_pretty(&app_state.files) {
+                // 导出文件是明文，不包含加密文件的密钥；这些文件需通过加密分享包分享
+                let mut files = app_state.files.clone();
+                let mut keys_removed = 0;
+                for entry in &mut files {
+                    if entry.file_key.take().is_some() {
+                        keys_removed += 1;
+                    }
+                }
+                match serde_json::to_string_pretty(&files) {
                     Ok(json) => match fs::write(&path, json) {
                         Ok(_) => {
+                            let path = path.to_string_lossy().into_owned();
+                            app_state.status_message = if keys_removed > 0 {
+                                t!(
+                                    "config-exported-without-keys",
+                                    path = path,
+                                    count = keys_removed
+                                )
+                            } else {
+                                t!("config-exported", path = path)
+                            };
+                        }
+                        Err(e) => {
                             app_state.status_message =
-                                format!("配置文件已导出到: {}", path.to_string_lossy())
+                                t!("export-config-failed", error = e.to_string())
                         }
-                        Err(e) => app_state.status_message = format!("导出配置文件失败: {}", e),
                     },
-                    Err(e) => app_state.status_message = format!("序列化文件列表失败: {}", e),
+                    Err(e) => {
+                        app_state.status_message =
+                            t!("serialize-files-failed", error = e.to_string())
+                    }
                 }
             } else {
-                app_state.status_message = "未选择导出路径。".into();
+                app_state.status_message = t!("no-export-path");
             }
             Command::none()
         }
@@ -384,47 +1401,84 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
         Message::ImportConfigSelected(path_opt) => {
             if let Some(path) = path_opt {
                 match fs::read_to_string(&path) {
-                    Ok(contents) => {
-                        match serde_json::from_str::<Vec<FileEntry>>(&contents) {
-                            Ok(imported_files) => {
-                                app_state.files = imported_files;
-                                save_file_entries(&app_state.files); // 保存到本地配置
-                                app_state.selected_files.clear(); // 清空选择
-                                app_state.status_message =
-                                    format!("配置文件已从 {} 导入。", path.to_string_lossy());
-                            }
-                            Err(e) => app_state.status_message = format!("解析导入文件失败: {}", e),
+                    Ok(contents) => match serde_json::from_str::<Vec<FileEntry>>(&contents) {
+                        Ok(imported_files) => {
+                            stage_import(
+                                app_state,
+                                path.to_string_lossy().into_owned(),
+                                imported_files,
+                                app_state.import_mode,
+                            );
                         }
-                    }
-                    Err(e) => app_state.status_message = format!("读取导入文件失败: {}", e),
+                        Err(e) => log_import_failure(
+                            app_state,
+                            &path.to_string_lossy(),
+                            t!("parse-import-failed", error = e.to_string()),
+                        ),
+                    },
+                    Err(e) => log_import_failure(
+                        app_state,
+                        &path.to_string_lossy(),
+                        t!("read-import-failed", error = e.to_string()),
+                    ),
                 }
             } else {
-                app_state.status_message = "未选择导入文件。".into();
+                app_state.status_message = t!("no-import-file");
             }
             Command::none()
         }
         Message::UploadConfigButtonPressed => {
-            app_state.status_message = "正在上传配置...".into();
-            let walrus_api = WalrusApi::default();
-            let config_json = match serde_json::to_string_pretty(&app_state.files) {
-                Ok(json) => json,
-                Err(e) => {
-                    app_state.status_message = format!("序列化配置失败: {}", e);
-                    return Command::none();
-                }
-            };
+            if app_state.config_passphrase.is_empty() {
+                app_state.status_message = t!("config-passphrase-required-for-upload");
+                return Command::none();
+            }
+            app_state.status_message = t!("uploading-config");
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            // 新快照链接到最近一次发布的快照
+            let previous = app_state
+                .config_history
+                .last()
+                .map(|record| record.id.clone());
+            let snapshot = ConfigSnapshot::new(app_state.files.clone(), previous);
+            let passphrase = app_state.config_passphrase.clone();
+            let epochs = app_state.settings.upload.snapshot_epochs;
 
+            let subject = t!("activity-config-subject", count = snapshot.files.len());
             Command::perform(
-                async move { walrus_api.upload_config_data(config_json).await },
-                |result| match result {
-                    Ok(blob_id) => Message::UploadConfigSuccess(blob_id),
-                    Err(e) => Message::StatusMessage(format!("配置上传失败: {}", e)),
-                },
+                timed(subject, async move {
+                    publish_snapshot(&walrus_api, snapshot, passphrase, epochs).await
+                }),
+                Message::UploadConfigComplete,
             )
         }
-        Message::UploadConfigSuccess(blob_id) => {
-            app_state.status_message =
-                format!("配置上传成功，ID: {} 已复制blobId", blob_id.clone());
+        Message::UploadConfigComplete(timed) => {
+            let record = match timed.result {
+                Ok(record) => record,
+                Err(e) => {
+                    app_state.status_message = t!("upload-config-failed", error = e.as_str());
+                    let record =
+                        ActivityRecord::new(ActivityKind::ConfigPush, timed.subject, false, e)
+                            .with_duration(timed.elapsed);
+                    log_activity(app_state, record);
+                    return Command::none();
+                }
+            };
+            log_activity(
+                app_state,
+                ActivityRecord::new(
+                    ActivityKind::ConfigPush,
+                    timed.subject,
+                    true,
+                    record.id.as_str(),
+                )
+                .with_duration(timed.elapsed),
+            );
+            let blob_id = record.id.clone();
+            app_state.status_message = t!("config-uploaded", id = blob_id.as_str());
+            if !app_state.config_history.iter().any(|r| r.id == record.id) {
+                app_state.config_history.push(record);
+                save_config_history(&app_state.workspace, &app_state.config_history);
+            }
             Command::perform(
                 async move {
                     let mut clipboard = arboard::Clipboard::new().unwrap();
@@ -437,32 +1491,755 @@ pub fn handle_message(app_state: &mut WalrusStore, message: Message) -> Command<
         Message::LoadConfigFromIdButtonPressed => {
             let id_to_load = app_state.download_id_input.clone();
             if id_to_load.is_empty() {
-                app_state.status_message = "请输入要加载的配置 ID。".into();
+                app_state.status_message = t!("enter-config-id");
                 return Command::none();
             }
-            app_state.status_message = format!("正在从 ID: {} 加载配置...", id_to_load);
-            let walrus_api = WalrusApi::default();
+            app_state.status_message = t!("loading-config", id = id_to_load.as_str());
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
             Command::perform(
                 async move { walrus_api.download_config_by_id(id_to_load).await },
                 Message::ConfigLoaded,
             )
         }
-        Message::ConfigLoaded(result) => {
+        Message::ConfigLoaded(result) => match result {
+            Ok(config_data) if is_encrypted_config(&config_data) => {
+                app_state.pending_encrypted_config = Some(config_data);
+                if app_state.config_passphrase.is_empty() {
+                    app_state.status_message = t!("config-is-encrypted");
+                    Command::none()
+                } else {
+                    handle_message(app_state, Message::DecryptConfigButtonPressed)
+                }
+            }
+            Ok(config_data) => {
+                // 旧版未加密的配置仍按明文 JSON 读取
+                stage_remote_config(app_state, &config_data);
+                Command::none()
+            }
+            Err(e) => {
+                let subject = app_state.download_id_input.clone();
+                log_import_failure(app_state, &subject, t!("load-config-failed", error = e));
+                Command::none()
+            }
+        },
+        Message::ConfigPassphraseChanged(passphrase) => {
+            app_state.config_passphrase = passphrase;
+            Command::none()
+        }
+        Message::DecryptConfigButtonPressed => {
+            let Some(config_data) = app_state.pending_encrypted_config.clone() else {
+                return Command::none();
+            };
+            if app_state.config_passphrase.is_empty() {
+                app_state.status_message = t!("enter-config-passphrase");
+                return Command::none();
+            }
+            app_state.status_message = t!("decrypting-config");
+            let passphrase = app_state.config_passphrase.clone();
+            Command::perform(
+                async move { decrypt_config(&config_data, &passphrase) },
+                Message::ConfigDecrypted,
+            )
+        }
+        Message::ConfigDecrypted(result) => {
             match result {
-                Ok(config_data) => match serde_json::from_str::<Vec<FileEntry>>(&config_data) {
-                    Ok(imported_files) => {
-                        app_state.files = imported_files;
-                        save_file_entries(&app_state.files);
-                        app_state.selected_files.clear();
-                        app_state.status_message = "配置已成功加载。".into();
+                Ok(config_data) => {
+                    app_state.pending_encrypted_config = None;
+                    stage_remote_config(app_state, &config_data);
+                }
+                Err(e) => {
+                    // 保留待解密的配置，允许用户重新输入密码
+                    let subject = app_state.download_id_input.clone();
+                    log_import_failure(app_state, &subject, t!("decrypt-config-failed", error = e));
+                }
+            }
+            Command::none()
+        }
+        Message::CancelDecryptConfig => {
+            app_state.pending_encrypted_config = None;
+            app_state.status_message = t!("config-load-cancelled");
+            Command::none()
+        }
+        Message::ImportModeChanged(mode) => {
+            // 只影响当前这次导入，之后的导入仍从默认模式开始
+            if let Some(pending) = app_state.pending_import.as_mut() {
+                pending.mode = mode;
+            }
+            Command::none()
+        }
+        Message::ConfirmImport => {
+            // 替换模式会移除本地记录，需要再次确认
+            if let Some(pending) = &app_state.pending_import
+                && pending.mode == ImportMode::Replace
+            {
+                let removed =
+                    preview_import(&app_state.files, &pending.incoming, ImportMode::Replace)
+                        .removed
+                        .len();
+                if removed > 0 {
+                    app_state.pending_confirmation = Some(ConfirmAction::ReplaceImport { removed });
+                    return Command::none();
+                }
+            }
+            apply_pending_import(app_state);
+            Command::none()
+        }
+        Message::CancelImport => {
+            app_state.pending_import = None;
+            app_state.status_message = t!("import-cancelled");
+            Command::none()
+        }
+        Message::UndoLastImport => {
+            if let Some(previous) = app_state.import_undo.take() {
+                app_state.files = previous;
+                // 替换导入时放入回收站的记录已经回到列表中
+                let files = &app_state.files;
+                app_state
+                    .trash
+                    .retain(|item| !files.iter().any(|f| f.id == item.entry.id));
+                save_file_entries(&app_state.workspace, &app_state.files);
+                save_trash(&app_state.workspace, &app_state.trash);
+                app_state.selected_files.clear();
+                app_state.status_message = t!("import-undone");
+            } else {
+                app_state.status_message = t!("nothing-to-undo");
+            }
+            Command::none()
+        }
+        Message::ToggleConfigHistory => {
+            app_state.show_config_history = !app_state.show_config_history;
+            app_state.snapshot_epochs_input = app_state.settings.upload.snapshot_epochs.to_string();
+            Command::none()
+        }
+        Message::FetchSnapshot(blob_id, action) => {
+            app_state.status_message = t!("loading-snapshot", id = blob_id.as_str());
+            let walrus_api = WalrusApi::new(&app_state.settings.network);
+            let passphrase = app_state.config_passphrase.clone();
+            Command::perform(
+                {
+                    let blob_id = blob_id.clone();
+                    async move { fetch_snapshot(&walrus_api, blob_id, passphrase).await }
+                },
+                move |result| Message::SnapshotFetched(blob_id.clone(), action, result),
+            )
+        }
+        Message::SnapshotFetched(blob_id, action, result) => {
+            match result {
+                Ok(snapshot) => match action {
+                    SnapshotAction::Diff => {
+                        app_state.status_message = t!("comparing-snapshot", id = blob_id.as_str());
+                        app_state.snapshot_diff = Some((blob_id, snapshot));
+                    }
+                    SnapshotAction::Restore => {
+                        app_state.show_config_history = false;
+                        stage_import(
+                            app_state,
+                            t!("import-source-snapshot", id = blob_id.as_str()),
+                            snapshot.files,
+                            app_state.import_mode,
+                        );
                     }
-                    Err(e) => app_state.status_message = format!("解析配置数据失败: {}", e),
                 },
                 Err(e) => {
-                    app_state.status_message = format!("加载配置失败: {}", e);
+                    app_state.status_message = t!("load-snapshot-failed", error = e);
+                }
+            }
+            Command::none()
+        }
+        Message::CloseSnapshotDiff => {
+            app_state.snapshot_diff = None;
+            Command::none()
+        }
+        Message::ToggleSyncPanel => {
+            app_state.show_sync_panel = !app_state.show_sync_panel;
+            Command::none()
+        }
+        Message::SyncEnabledToggled(enabled) => {
+            app_state.settings.sync.enabled = enabled;
+            save_settings(&app_state.workspace, &app_state.settings);
+            app_state.status_message = if enabled {
+                t!(
+                    "sync-enabled",
+                    minutes = app_state.settings.sync.interval_minutes
+                )
+            } else {
+                t!("sync-disabled")
+            };
+            Command::none()
+        }
+        Message::SyncPointerPathChanged(path) => {
+            app_state.settings.sync.pointer_path = path;
+            save_settings(&app_state.workspace, &app_state.settings);
+            Command::none()
+        }
+        Message::TriggerSyncPointerSelection => Command::perform(
+            async {
+                let initial_directory = UserDirs::new()
+                    .and_then(|user_dirs| user_dirs.document_dir().map(|path| path.to_path_buf()))
+                    .unwrap_or_else(|| PathBuf::from("."));
+
+                let pick_result = AsyncFileDialog::new()
+                    .set_directory(initial_directory)
+                    .set_file_name("walrus_store_sync.json")
+                    .save_file()
+                    .await;
+                Message::SyncPointerSelected(pick_result.map(|handle| handle.path().to_path_buf()))
+            },
+            |msg| msg,
+        ),
+        Message::SyncPointerSelected(path_opt) => {
+            if let Some(path) = path_opt {
+                app_state.settings.sync.pointer_path = path.to_string_lossy().into_owned();
+                save_settings(&app_state.workspace, &app_state.settings);
+                app_state.status_message = t!(
+                    "sync-pointer-set",
+                    path = app_state.settings.sync.pointer_path.as_str()
+                );
+            } else {
+                app_state.status_message = t!("no-sync-pointer-selected");
+            }
+            Command::none()
+        }
+        Message::SyncIntervalChanged(input) => {
+            if let Ok(minutes) = input.trim().parse::<u64>()
+                && minutes > 0
+            {
+                app_state.settings.sync.interval_minutes = minutes;
+                save_settings(&app_state.workspace, &app_state.settings);
+            }
+            app_state.sync_interval_input = input;
+            Command::none()
+        }
+        Message::SnapshotEpochsChanged(input) => {
+            if let Ok(epochs) = parse_epochs(&input) {
+                app_state.settings.upload.snapshot_epochs = epochs;
+                save_settings(&app_state.workspace, &app_state.settings);
+            }
+            app_state.snapshot_epochs_input = input;
+            Command::none()
+        }
+        Message::SyncNowPressed => start_sync(app_state, true),
+        Message::SyncTick => start_sync(app_state, false),
+        Message::SyncCompleted(timed) => {
+            app_state.sync_in_progress = false;
+            let success = timed.result.is_ok();
+            match timed.result {
+                Ok(outcome) => {
+                    // 同步期间本地列表若有改动，以同步开始时的列表为基准再合并一次
+                    app_state.files = if app_state.files == outcome.local_at_start {
+                        outcome.merged.clone()
+                    } else {
+                        three_way_merge(&outcome.local_at_start, &app_state.files, &outcome.merged)
+                            .files
+                    };
+                    save_file_entries(&app_state.workspace, &app_state.files);
+                    app_state.sync_state = SyncState {
+                        last_synced_id: outcome.synced_id.clone(),
+                        last_synced_at: Some(Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()),
+                        base: outcome.merged,
+                    };
+                    save_sync_state(&app_state.workspace, &app_state.sync_state);
+                    if let Some(record) = outcome.published
+                        && !app_state.config_history.iter().any(|r| r.id == record.id)
+                    {
+                        app_state.config_history.push(record);
+                        save_config_history(&app_state.workspace, &app_state.config_history);
+                    }
+                    app_state.last_sync_conflicts = outcome.conflicts;
+                    app_state.status_message = if app_state.last_sync_conflicts.is_empty() {
+                        t!("sync-completed")
+                    } else {
+                        t!(
+                            "sync-completed-with-conflicts",
+                            count = app_state.last_sync_conflicts.len()
+                        )
+                    };
+                }
+                Err(e) => {
+                    app_state.status_message = t!("sync-failed", error = e);
+                }
+            }
+            let detail = app_state.status_message.clone();
+            log_activity(
+                app_state,
+                ActivityRecord::new(ActivityKind::Sync, timed.subject, success, detail)
+                    .with_duration(timed.elapsed),
+            );
+            Command::none()
+        }
+        Message::WorkspaceSelected(workspace) => {
+            if workspace == app_state.workspace {
+                return Command::none();
+            }
+            if app_state.sync_in_progress {
+                app_state.status_message = t!("sync-busy-switching-workspace");
+                return Command::none();
+            }
+            // 上传和探测的结果会写入当前工作区，完成前不允许切换
+            if app_state.upload_in_progress
+                || !app_state.upload_queue.is_empty()
+                || app_state.probes_in_progress > 0
+            {
+                app_state.status_message = t!("transfer-busy-switching-workspace");
+                return Command::none();
+            }
+            save_active_workspace(&workspace);
+            load_workspace(app_state, workspace);
+            app_state.status_message =
+                t!("workspace-switched", name = app_state.workspace.as_str());
+            Command::none()
+        }
+        Message::ToggleWorkspacePanel => {
+            app_state.show_workspace_panel = !app_state.show_workspace_panel;
+            app_state.network_input = app_state.settings.network.clone();
+            app_state.workspaces = list_workspaces();
+            Command::none()
+        }
+        Message::NewWorkspaceNameChanged(name) => {
+            app_state.new_workspace_name = name;
+            Command::none()
+        }
+        Message::CreateWorkspacePressed => {
+            match create_workspace(&app_state.new_workspace_name) {
+                Ok(name) => {
+                    app_state.new_workspace_name = String::new();
+                    app_state.workspaces = list_workspaces();
+                    app_state.status_message = t!("workspace-created", name = name.as_str());
                 }
+                Err(e) => app_state.status_message = t!("create-workspace-failed", error = e),
             }
             Command::none()
         }
+        Message::DeleteWorkspacePressed(name) => {
+            if name == app_state.workspace {
+                app_state.status_message = t!("cannot-delete-active-workspace");
+            } else {
+                app_state.pending_confirmation = Some(ConfirmAction::DeleteWorkspace(name));
+            }
+            Command::none()
+        }
+        Message::AggregatorUrlChanged(url) => {
+            app_state.network_input.aggregator_url = url;
+            Command::none()
+        }
+        Message::PublisherUrlChanged(url) => {
+            app_state.network_input.publisher_url = url;
+            Command::none()
+        }
+        Message::SaveNetworkProfilePressed => {
+            let profile = app_state.network_input.clone();
+            match WalrusClient::new(&profile.aggregator_url, &profile.publisher_url) {
+                Ok(_) => {
+                    app_state.settings.network = profile;
+                    save_settings(&app_state.workspace, &app_state.settings);
+                    app_state.status_message =
+                        t!("network-profile-saved", name = app_state.workspace.as_str());
+                }
+                Err(e) => {
+                    app_state.status_message = t!("invalid-network-profile", error = e.to_string())
+                }
+            }
+            Command::none()
+        }
+        Message::TransferTargetSelected(target) => {
+            app_state.transfer_target = Some(target);
+            Command::none()
+        }
+        Message::TransferSelectedEntries(mode) => {
+            let Some(target) = app_state.transfer_target.clone() else {
+                app_state.status_message = t!("select-target-workspace");
+                return Command::none();
+            };
+            if target == app_state.workspace {
+                app_state.status_message = t!("target-is-current-workspace");
+                return Command::none();
+            }
+            if app_state.selected_files.is_empty() {
+                app_state.status_message = t!("nothing-selected");
+                return Command::none();
+            }
+            let entries: Vec<FileEntry> = app_state
+                .files
+                .iter()
+                .filter(|f| app_state.selected_files.contains(&f.id))
+                .cloned()
+                .collect();
+            let added = copy_entries_to_workspace(&entries, &target);
+            if mode == TransferMode::Move {
+                app_state
+                    .files
+                    .retain(|f| !app_state.selected_files.contains(&f.id));
+                save_file_entries(&app_state.workspace, &app_state.files);
+                app_state.selected_files.clear();
+            }
+            let key = match mode {
+                TransferMode::Copy => "entries-copied",
+                TransferMode::Move => "entries-moved",
+            };
+            app_state.status_message = t!(
+                key,
+                count = entries.len(),
+                target = target.as_str(),
+                added = added
+            );
+            Command::none()
+        }
+    }
+}
+
+// 应用导入预览；替换模式下被移除的记录放入回收站
+fn apply_pending_import(app_state: &mut WalrusStore) {
+    let Some(pending) = app_state.pending_import.take() else {
+        return;
+    };
+    let previous = app_state.files.clone();
+    app_state.files = apply_import(&previous, &pending.incoming, pending.mode);
+    let files = &app_state.files;
+    let removed = previous
+        .iter()
+        .filter(|entry| !files.iter().any(|f| f.id == entry.id))
+        .cloned();
+    trash_entries(&mut app_state.trash, removed, &trash::now());
+    app_state.import_undo = Some(previous);
+    save_file_entries(&app_state.workspace, &app_state.files); // 保存到本地配置
+    save_trash(&app_state.workspace, &app_state.trash);
+    app_state.selected_files.clear(); // 清空选择
+    app_state.status_message = t!(
+        "config-imported",
+        source = pending.source.as_str(),
+        mode = pending.mode.to_string(),
+        count = app_state.files.len()
+    );
+    let detail = t!(
+        "activity-import-detail",
+        mode = pending.mode.to_string(),
+        count = pending.incoming.len()
+    );
+    log_activity(
+        app_state,
+        ActivityRecord::new(ActivityKind::Import, pending.source, true, detail),
+    );
+}
+
+// 清除回收站中超过保留期的记录
+fn set_retention_days(app_state: &mut WalrusStore, days: u32) {
+    app_state.settings.trash.retention_days = days;
+    app_state.trash_retention_input = days.to_string();
+    save_settings(&app_state.workspace, &app_state.settings);
+    purge_expired_trash(app_state);
+}
+
+fn purge_expired_trash(app_state: &mut WalrusStore) {
+    let retention_days = app_state.settings.trash.retention_days;
+    if purge_expired(&mut app_state.trash, retention_days, &trash::now()) > 0 {
+        save_trash(&app_state.workspace, &app_state.trash);
+    }
+}
+
+// 加载工作区的文件列表、设置和同步状态，并清空与上一个工作区相关的临时状态
+pub fn load_workspace(app_state: &mut WalrusStore, workspace: String) {
+    app_state.files = load_file_entries(&workspace);
+    app_state.config_history = load_config_history(&workspace);
+    app_state.settings = load_settings(&workspace);
+    app_state.sync_state = load_sync_state(&workspace);
+    app_state.sync_interval_input = app_state.settings.sync.interval_minutes.to_string();
+    app_state.snapshot_epochs_input = app_state.settings.upload.snapshot_epochs.to_string();
+    app_state.network_input = app_state.settings.network.clone();
+    app_state.trash = load_trash(&workspace);
+    app_state.trash_retention_input = app_state.settings.trash.retention_days.to_string();
+    app_state.sui_address_input = app_state.settings.chain.sui_address.clone();
+    app_state.keystore_addresses.clear();
+    app_state.spend = load_spend(&workspace);
+    app_state.activity = load_activity(&workspace);
+    app_state.price_status = PriceStatus::Unknown;
+    app_state.pending_confirmation = None;
+    app_state.selected_files.clear();
+    app_state.pending_import = None;
+    app_state.import_undo = None;
+    app_state.pending_encrypted_config = None;
+    app_state.snapshot_diff = None;
+    app_state.preview = None;
+    app_state.share_dialog = None;
+    app_state.open_bundle = None;
+    app_state.last_sync_conflicts.clear();
+    app_state.workspaces = list_workspaces();
+    if app_state.transfer_target.as_ref() == Some(&workspace) {
+        app_state.transfer_target = None;
+    }
+    app_state.workspace = workspace;
+    purge_expired_trash(app_state);
+}
+
+// 重新读取自定义主题并应用设置中选择的主题，主题文件修改后切换一次即可生效
+pub fn apply_theme(app_state: &mut WalrusStore) {
+    app_state.themes = merge_user_themes(load_user_themes());
+    let name = &app_state.preferences.theme;
+    let palette = find_theme(&app_state.themes, name);
+    set_palette(palette);
+    app_state.ui_theme = palette.to_iced_theme(name);
+}
+
+// 根据当前网络配置和选项重新生成分享链接和二维码
+fn refresh_share_link(app_state: &mut WalrusStore) {
+    let walrus_api = WalrusApi::new(&app_state.settings.network);
+    let Some(dialog) = app_state.share_dialog.as_mut() else {
+        return;
+    };
+    if dialog.encrypted {
+        return;
+    }
+    let file_name = dialog.include_name.then_some(dialog.name.as_str());
+    match walrus_api.share_url(&dialog.id, file_name) {
+        Ok(url) => {
+            dialog.qr = match QrMatrix::encode(&url) {
+                Ok(qr) => Some(qr),
+                Err(e) => {
+                    app_state.status_message = e;
+                    None
+                }
+            };
+            dialog.url = url;
+        }
+        Err(e) => {
+            app_state.status_message = t!("share-link-failed", error = e);
+        }
     }
 }
+
+// 把文件加入上传队列；当前没有上传时立即开始上传第一个
+fn enqueue_uploads(app_state: &mut WalrusStore, jobs: Vec<UploadJob>) -> Command<Message> {
+    app_state.upload_queue.extend(jobs);
+    if app_state.upload_in_progress {
+        app_state.status_message = t!("upload-queued", count = app_state.upload_queue.len());
+        return Command::none();
+    }
+    start_next_upload(app_state)
+}
+
+// 通过 walrus 命令行客户端查询网络价格，用于估算上传费用
+fn fetch_prices(app_state: &mut WalrusStore) -> Command<Message> {
+    app_state.price_status = PriceStatus::Loading;
+    let cli = WalrusCli::new(&app_state.settings.chain);
+    Command::perform(
+        async move { cli.price_info().await },
+        Message::PriceInfoLoaded,
+    )
+}
+
+fn start_next_upload(app_state: &mut WalrusStore) -> Command<Message> {
+    let Some(job) = app_state.upload_queue.pop_front() else {
+        return Command::none();
+    };
+    app_state.upload_in_progress = true;
+    app_state.status_message = t!("uploading", name = job.name.as_str());
+    app_state.upload_progress = 0.0;
+
+    let walrus_api = WalrusApi::new(&app_state.settings.network); // 创建 WalrusApi 实例
+    let upload = &app_state.settings.upload;
+    let options = UploadOptions {
+        epochs: job.epochs,
+        wrap_metadata: upload.wrap_metadata,
+        encrypt: upload.encrypt,
+        deletable: upload.deletable,
+        send_object_to: Some(app_state.settings.chain.sui_address.clone())
+            .filter(|address| !address.is_empty()),
+    };
+    Command::perform(
+        timed(job.name.clone(), async move {
+            let uploaded = walrus_api
+                .upload_file(job.path.clone(), &job.name, options.clone())
+                .await?;
+            // 上传图片时顺便生成缩略图，失败不影响上传结果；
+            // 缩略图缓存是明文且各工作区共用，加密上传的图片不生成
+            if uploaded.metadata.mime.starts_with("image/")
+                && uploaded.file_key.is_none()
+                && let Err(e) = fs::read(&job.path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|data| cache_thumbnail(&uploaded.id, &data))
+            {
+                eprintln!("生成缩略图失败: {}", e);
+            }
+            let mut entry = FileEntry::new(uploaded.id, job.name).with_metadata(&uploaded.metadata);
+            entry.tags = job.tags;
+            entry.epochs = Some(job.epochs);
+            entry.file_key = uploaded.file_key;
+            entry.deletable = uploaded.deletable;
+            // 只有新建的 blob 对象会发送到指定地址
+            if uploaded.object_id.is_some() {
+                entry.owner = options.send_object_to;
+            }
+            entry.object_id = uploaded.object_id;
+            entry.cost = Some(uploaded.cost);
+            Ok(entry)
+        }),
+        Message::UploadComplete,
+    )
+}
+
+// 网格视图中为图片记录准备缩略图：优先使用本地缓存，没有缓存时在后台下载并生成
+// 每次处理消息后调用，同时进行的下载不超过 MAX_PENDING_THUMBNAILS 个
+pub fn request_thumbnails(app_state: &mut WalrusStore) -> Command<Message> {
+    if !app_state.settings.display.grid_view {
+        return Command::none();
+    }
+    let mut pending = app_state
+        .thumbnails
+        .values()
+        .filter(|status| matches!(status, ThumbnailStatus::Pending))
+        .count();
+    let mut commands = Vec::new();
+    for entry in &app_state.files {
+        if app_state.thumbnails.contains_key(&entry.id) {
+            continue;
+        }
+        // 加密文件不缓存明文缩略图
+        if !is_image_entry(entry) || entry.file_key.is_some() {
+            app_state
+                .thumbnails
+                .insert(entry.id.clone(), ThumbnailStatus::Unavailable);
+            continue;
+        }
+        if let Some(path) = cached_thumbnail(&entry.id) {
+            app_state.thumbnails.insert(
+                entry.id.clone(),
+                ThumbnailStatus::Ready(iced::widget::image::Handle::from_path(path)),
+            );
+            continue;
+        }
+        if pending >= MAX_PENDING_THUMBNAILS {
+            continue;
+        }
+        pending += 1;
+        app_state
+            .thumbnails
+            .insert(entry.id.clone(), ThumbnailStatus::Pending);
+        let walrus_api = WalrusApi::new(&app_state.settings.network);
+        let id = entry.id.clone();
+        commands.push(Command::perform(
+            async move {
+                let (data, truncated) = walrus_api
+                    .fetch_blob_prefix(&id, PREVIEW_SIZE_LIMIT)
+                    .await?;
+                if truncated {
+                    return Err(t!("image-too-large"));
+                }
+                let (_, content) = blob_envelope::unwrap(data)?;
+                cache_thumbnail(&id, &content).map(|path| (id, path))
+            },
+            {
+                let id = entry.id.clone();
+                move |result| match result {
+                    Ok((id, path)) => Message::ThumbnailLoaded(id, Ok(path)),
+                    Err(e) => Message::ThumbnailLoaded(id, Err(e)),
+                }
+            },
+        ));
+    }
+    Command::batch(commands)
+}
+
+// 开始一次同步；manual 为 false 时（定时触发）条件不满足则静默跳过
+fn start_sync(app_state: &mut WalrusStore, manual: bool) -> Command<Message> {
+    let sync = &app_state.settings.sync;
+    if app_state.sync_in_progress || (!manual && !sync.enabled) {
+        return Command::none();
+    }
+    if sync.pointer_path.is_empty() {
+        if manual {
+            app_state.status_message = t!("sync-pointer-required");
+        }
+        return Command::none();
+    }
+    if app_state.config_passphrase.is_empty() {
+        if manual {
+            app_state.status_message = t!("sync-passphrase-required");
+        }
+        return Command::none();
+    }
+    app_state.sync_in_progress = true;
+    app_state.status_message = t!("syncing");
+    let walrus_api = WalrusApi::new(&app_state.settings.network);
+    Command::perform(
+        timed(
+            sync.pointer_path.clone(),
+            run_sync(
+                walrus_api,
+                PathBuf::from(&sync.pointer_path),
+                app_state.config_passphrase.clone(),
+                app_state.sync_state.clone(),
+                app_state.files.clone(),
+                app_state.settings.upload.snapshot_epochs,
+            ),
+        ),
+        Message::SyncCompleted,
+    )
+}
+
+// 解析远程配置（已解密或旧版明文）并进入导入预览
+fn stage_remote_config(app_state: &mut WalrusStore, config_data: &str) {
+    match parse_config_payload(config_data) {
+        Ok(snapshot) => {
+            let source = t!(
+                "import-source-config",
+                id = app_state.download_id_input.as_str()
+            );
+            let mode = app_state.import_mode;
+            stage_import(app_state, source, snapshot.files, mode);
+        }
+        Err(e) => {
+            let subject = app_state.download_id_input.clone();
+            log_import_failure(app_state, &subject, e);
+        }
+    }
+}
+
+// 导入失败时在状态栏显示错误，同时写入活动日志
+fn log_import_failure(app_state: &mut WalrusStore, subject: &str, error: String) {
+    app_state.status_message = error.clone();
+    log_activity(
+        app_state,
+        ActivityRecord::new(ActivityKind::Import, subject, false, error),
+    );
+}
+
+fn log_activity(app_state: &mut WalrusStore, record: ActivityRecord) {
+    activity::append(&mut app_state.activity, record);
+    save_activity(&app_state.workspace, &app_state.activity);
+}
+
+// 是否有对话框或面板代替文件列表显示（与 ui::view_application 中的顺序对应）
+fn overlay_open(app_state: &WalrusStore) -> bool {
+    app_state.command_palette.is_some()
+        || app_state.pending_confirmation.is_some()
+        || app_state.pending_encrypted_config.is_some()
+        || !app_state.download_conflicts.is_empty()
+        || app_state.share_dialog.is_some()
+        || app_state.open_bundle.is_some()
+        || app_state.pending_import.is_some()
+        || app_state.snapshot_diff.is_some()
+        || app_state.show_workspace_panel
+        || app_state.show_activity_panel
+        || app_state.show_trash_panel
+        || app_state.show_identity_panel
+        || app_state.show_sync_panel
+        || app_state.show_config_history
+}
+
+// 解析成功的导入先进入预览，由用户确认后再应用
+fn stage_import(
+    app_state: &mut WalrusStore,
+    source: String,
+    incoming: Vec<FileEntry>,
+    mode: ImportMode,
+) {
+    app_state.status_message = t!(
+        "import-staged",
+        source = source.as_str(),
+        count = incoming.len()
+    );
+    app_state.pending_import = Some(PendingImport {
+        source,
+        incoming,
+        mode,
+    });
+}
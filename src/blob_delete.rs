@@ -0,0 +1,206 @@
+use crate::data::FileEntry;
+use crate::i18n::t;
+use crate::settings::ChainSettings;
+use crate::wallet::validate_sui_address;
+use crate::walrus_api::validate_blob_id;
+use std::future::Future;
+
+// 删除 Walrus 上的 blob 需要持有 blob 对象的 Sui 地址签名交易，发布器的 HTTP 接口不提供删除，
+// 所以通过 walrus 命令行客户端（使用它配置的钱包）完成；测试中使用本地替身
+pub trait BlobDeleter {
+    fn delete_blob(&self, target: &DeleteTarget)
+    -> impl Future<Output = Result<(), String>> + Send;
+}
+
+// 删除的对象：知道 blob 对象 ID 时只删除这一个对象；
+// 按 blob ID 删除会删除钱包持有的所有同一 blob ID 的对象
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeleteTarget {
+    Object(String),
+    Blob(String),
+}
+
+// 调用 walrus 命令行客户端（删除 blob、查询网络价格）
+#[derive(Debug, Clone)]
+pub struct WalrusCli {
+    binary: String,
+    config_path: String,
+}
+
+impl WalrusCli {
+    pub fn new(settings: &ChainSettings) -> Self {
+        Self {
+            binary: settings.walrus_cli.clone(),
+            config_path: settings.walrus_config.clone(),
+        }
+    }
+
+    // 子命令前加上配置文件参数
+    fn args(&self, subcommand: &[&str]) -> Vec<String> {
+        let mut args = Vec::new();
+        if !self.config_path.trim().is_empty() {
+            args.extend(["--config".to_string(), self.config_path.trim().to_string()]);
+        }
+        args.extend(subcommand.iter().map(|arg| arg.to_string()));
+        args
+    }
+
+    fn delete_args(&self, target: &DeleteTarget) -> Vec<String> {
+        match target {
+            DeleteTarget::Object(object_id) => {
+                self.args(&["delete", "--object-id", object_id, "--yes"])
+            }
+            DeleteTarget::Blob(blob_id) => self.args(&["delete", "--blob-id", blob_id, "--yes"]),
+        }
+    }
+
+    // 运行子命令，成功时返回标准输出
+    pub async fn run(&self, subcommand: &[&str]) -> Result<String, String> {
+        self.run_args(self.args(subcommand)).await
+    }
+
+    async fn run_args(&self, args: Vec<String>) -> Result<String, String> {
+        let output = tokio::process::Command::new(&self.binary)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| {
+                t!(
+                    "walrus-cli-unavailable",
+                    binary = self.binary.as_str(),
+                    error = e.to_string()
+                )
+            })?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(t!("walrus-cli-failed", error = stderr.trim()))
+        }
+    }
+}
+
+impl BlobDeleter for WalrusCli {
+    async fn delete_blob(&self, target: &DeleteTarget) -> Result<(), String> {
+        self.run_args(self.delete_args(target)).await.map(|_| ())
+    }
+}
+
+// 只有以可删除方式存储、且 blob 对象发送到了当前配置的 Sui 地址时，才能由本机钱包删除
+pub fn can_delete(entry: &FileEntry, own_address: &str) -> bool {
+    entry.deletable && !own_address.is_empty() && entry.owner.as_deref() == Some(own_address)
+}
+
+// 记录来自本地目录或导入的配置，传给命令行前先校验 ID 的格式
+fn delete_target(entry: &FileEntry) -> Result<DeleteTarget, String> {
+    let blob_id = validate_blob_id(&entry.id)?;
+    match &entry.object_id {
+        Some(object_id) => validate_sui_address(object_id)
+            .map(DeleteTarget::Object)
+            .map_err(|_| t!("invalid-object-id", id = object_id.as_str())),
+        None => Ok(DeleteTarget::Blob(blob_id)),
+    }
+}
+
+// 成功时返回被删除的 blob ID
+pub async fn delete_from_walrus(
+    deleter: &impl BlobDeleter,
+    entry: &FileEntry,
+    own_address: &str,
+) -> Result<String, String> {
+    if !entry.deletable {
+        return Err(t!("blob-not-deletable", name = entry.name.as_str()));
+    }
+    if !can_delete(entry, own_address) {
+        return Err(t!("blob-not-owned", name = entry.name.as_str()));
+    }
+    deleter.delete_blob(&delete_target(entry)?).await?;
+    Ok(entry.id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    // 本地替身：记录可删除的 blob 对象，删除后不再存在
+    struct LocalStandIn {
+        deletable: Mutex<HashSet<DeleteTarget>>,
+    }
+
+    impl BlobDeleter for LocalStandIn {
+        async fn delete_blob(&self, target: &DeleteTarget) -> Result<(), String> {
+            if self.deletable.lock().unwrap().remove(target) {
+                Ok(())
+            } else {
+                Err(format!("no deletable blob {:?}", target))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn only_owned_deletable_blobs_are_deleted() {
+        let blob_id = "a".repeat(43);
+        let object_id = format!("0x{}", "1".repeat(64));
+        let own_address = format!("0x{}", "2".repeat(64));
+        let stand_in = LocalStandIn {
+            deletable: Mutex::new(HashSet::from([DeleteTarget::Object(object_id.clone())])),
+        };
+        let mut entry = FileEntry::new(blob_id.clone(), "a.txt".into());
+        entry.object_id = Some(object_id.clone());
+        assert!(
+            delete_from_walrus(&stand_in, &entry, &own_address)
+                .await
+                .is_err()
+        );
+
+        entry.deletable = true;
+        assert!(!can_delete(&entry, &own_address));
+        assert!(
+            delete_from_walrus(&stand_in, &entry, &own_address)
+                .await
+                .is_err()
+        );
+        entry.owner = Some(own_address.clone());
+        assert!(!can_delete(&entry, ""));
+        assert_eq!(
+            delete_from_walrus(&stand_in, &entry, &own_address)
+                .await
+                .unwrap(),
+            blob_id
+        );
+        assert!(
+            delete_from_walrus(&stand_in, &entry, &own_address)
+                .await
+                .is_err()
+        );
+
+        // 导入的记录中 ID 格式不对时不会传给命令行
+        entry.object_id = Some("--help".into());
+        assert!(delete_target(&entry).is_err());
+        entry.object_id = None;
+        assert_eq!(
+            delete_target(&entry).unwrap(),
+            DeleteTarget::Blob(blob_id.clone())
+        );
+        entry.id = "a --yes".into();
+        assert!(delete_target(&entry).is_err());
+
+        let cli = WalrusCli::new(&ChainSettings {
+            walrus_config: " client.yaml ".into(),
+            ..Default::default()
+        });
+        assert_eq!(
+            cli.delete_args(&DeleteTarget::Object("0x1".into()))
+                .join(" "),
+            "--config client.yaml delete --object-id 0x1 --yes"
+        );
+        assert_eq!(
+            WalrusCli::new(&ChainSettings::default())
+                .delete_args(&DeleteTarget::Blob("a".into()))
+                .join(" "),
+            "delete --blob-id a --yes"
+        );
+    }
+}
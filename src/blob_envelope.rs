@@ -0,0 +1,139 @@
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// 元数据信封格式：MAGIC + 4 字节大端头部长度 + JSON 头部 + 原始内容
+const ENVELOPE_MAGIC: &[u8; 8] = b"WSENV\x00\x01\n";
+const MAX_HEADER_LEN: usize = 64 * 1024;
+
+// 随文件一起保存的元数据，下载时用于恢复文件名和类型
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlobMetadata {
+    pub name: String,
+    pub mime: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+impl BlobMetadata {
+    pub fn describe(name: &str, data: &[u8]) -> Self {
+        Self {
+            name: name.to_string(),
+            mime: guess_mime(data),
+            size: data.len() as u64,
+            sha256: sha256_hex(data),
+        }
+    }
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+// 根据内容猜测 MIME 类型，无法识别的文本按纯文本处理
+pub fn guess_mime(data: &[u8]) -> String {
+    if let Some(kind) = infer::get(data) {
+        kind.mime_type().to_string()
+    } else if std::str::from_utf8(data).is_ok() {
+        "text/plain".to_string()
+    } else {
+        "application/octet-stream".to_string()
+    }
+}
+
+// 为没有元数据的 blob 根据文件头猜测扩展名
+pub fn sniff_extension(data: &[u8]) -> Option<&'static str> {
+    if let Some(kind) = infer::get(data) {
+        Some(kind.extension())
+    } else if !data.is_empty() && std::str::from_utf8(data).is_ok() {
+        Some("txt")
+    } else {
+        None
+    }
+}
+
+pub fn wrap(metadata: &BlobMetadata, content: &[u8]) -> Result<Vec<u8>, String> {
+    let header = serde_json::to_vec(metadata)
+        .map_err(|e| t!("serialize-metadata-failed", error = e.to_string()))?;
+    let mut data = Vec::with_capacity(ENVELOPE_MAGIC.len() + 4 + header.len() + content.len());
+    data.extend_from_slice(ENVELOPE_MAGIC);
+    data.extend_from_slice(&(header.len() as u32).to_be_bytes());
+    data.extend_from_slice(&header);
+    data.extend_from_slice(content);
+    Ok(data)
+}
+
+// 解析信封头部，返回元数据和内容的起始位置；不是信封格式时返回 None
+fn parse_header(data: &[u8]) -> Result<Option<(BlobMetadata, usize)>, String> {
+    let Some(rest) = data.strip_prefix(ENVELOPE_MAGIC.as_slice()) else {
+        return Ok(None);
+    };
+    if rest.len() < 4 {
+        return Err(t!("envelope-corrupted"));
+    }
+    let header_len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+    if header_len > MAX_HEADER_LEN || rest.len() < 4 + header_len {
+        return Err(t!("envelope-corrupted"));
+    }
+    let metadata: BlobMetadata = serde_json::from_slice(&rest[4..4 + header_len])
+        .map_err(|e| t!("parse-metadata-failed", error = e.to_string()))?;
+    Ok(Some((metadata, ENVELOPE_MAGIC.len() + 4 + header_len)))
+}
+
+// 拆开元数据信封；不是信封格式的数据原样返回，元数据为 None
+pub fn unwrap(data: Vec<u8>) -> Result<(Option<BlobMetadata>, Vec<u8>), String> {
+    let Some((metadata, offset)) = parse_header(&data)? else {
+        return Ok((None, data));
+    };
+    let content = data[offset..].to_vec();
+    if content.len() as u64 != metadata.size || sha256_hex(&content) != metadata.sha256 {
+        return Err(t!("envelope-mismatch"));
+    }
+    Ok((Some(metadata), content))
+}
+
+// 只读取了开头部分的数据：头部完整即可取得元数据，内容不完整，无法校验哈希
+pub fn unwrap_prefix(data: Vec<u8>) -> Result<(Option<BlobMetadata>, Vec<u8>), String> {
+    let Some((metadata, offset)) = parse_header(&data)? else {
+        return Ok((None, data));
+    };
+    Ok((Some(metadata), data[offset..].to_vec()))
+}
+
+// 没有文件名时的默认名称，尽量根据内容补上扩展名
+pub fn fallback_file_name(blob_id: &str, content: &[u8]) -> String {
+    match sniff_extension(content) {
+        Some(extension) => format!("downloaded_file_{}.{}", blob_id, extension),
+        None => format!("downloaded_file_{}", blob_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_round_trip_and_plain_blobs() {
+        let content = b"%PDF-1.4 fake pdf";
+        let metadata = BlobMetadata::describe("report.pdf", content);
+        assert_eq!(metadata.mime, "application/pdf");
+
+        let wrapped = wrap(&metadata, content).unwrap();
+        let (unwrapped_metadata, unwrapped) = unwrap(wrapped.clone()).unwrap();
+        assert_eq!(unwrapped_metadata, Some(metadata));
+        assert_eq!(unwrapped, content);
+
+        let (prefix_metadata, prefix) =
+            unwrap_prefix(wrapped[..wrapped.len() - 4].to_vec()).unwrap();
+        assert_eq!(prefix_metadata.unwrap().name, "report.pdf");
+        assert_eq!(prefix, &content[..content.len() - 4]);
+
+        let mut tampered = wrapped;
+        *tampered.last_mut().unwrap() ^= 0xFF;
+        assert!(unwrap(tampered).is_err());
+
+        let (none, plain) = unwrap(content.to_vec()).unwrap();
+        assert_eq!(none, None);
+        assert_eq!(fallback_file_name("abc", &plain), "downloaded_file_abc.pdf");
+    }
+}
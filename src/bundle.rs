@@ -0,0 +1,201 @@
+use crate::data::FileEntry;
+use crate::i18n::t;
+use crate::identity::{Identity, SealedPayload, open, seal};
+use crate::walrus_api::WalrusApi;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+const BUNDLE_FORMAT: &str = "walrus_store_share_bundle";
+const BUNDLE_VERSION: u32 = 1;
+const SEALED_BUNDLE_FORMAT: &str = "walrus_store_sealed_bundle";
+
+// 分享包清单：一组文件记录作为单个 blob 发布，接收方只需要清单 ID
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShareBundle {
+    pub format: String,
+    pub version: u32,
+    pub created_at: String,
+    pub entries: Vec<BundleEntry>,
+}
+
+// 清单中的文件，只包含接收方下载和校验需要的信息，不包含本地标签等
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_key: Option<String>, // 加密文件的密钥，只会出现在加密分享包中
+}
+
+// 加密分享包：清单整体按接收人加密，只有列出的公钥对应的私钥才能打开
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SealedBundle {
+    format: String,
+    version: u32,
+    #[serde(flatten)]
+    payload: SealedPayload,
+}
+
+impl From<&FileEntry> for BundleEntry {
+    fn from(entry: &FileEntry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            name: entry.name.clone(),
+            size: entry.size,
+            mime: entry.mime.clone(),
+            sha256: entry.sha256.clone(),
+            file_key: entry.file_key.clone(),
+        }
+    }
+}
+
+impl BundleEntry {
+    // 导入到文件列表时的记录，上传时间为导入时间
+    pub fn to_file_entry(&self) -> FileEntry {
+        FileEntry {
+            size: self.size,
+            mime: self.mime.clone(),
+            sha256: self.sha256.clone(),
+            file_key: self.file_key.clone(),
+            ..FileEntry::new(self.id.clone(), self.name.clone())
+        }
+    }
+}
+
+impl ShareBundle {
+    pub fn new(entries: &[FileEntry]) -> Self {
+        Self {
+            format: BUNDLE_FORMAT.into(),
+            version: BUNDLE_VERSION,
+            created_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            entries: entries.iter().map(BundleEntry::from).collect(),
+        }
+    }
+
+    pub fn total_size(&self) -> Option<u64> {
+        self.entries.iter().map(|entry| entry.size).sum()
+    }
+
+    pub fn has_file_keys(&self) -> bool {
+        self.entries.iter().any(|entry| entry.file_key.is_some())
+    }
+}
+
+pub fn parse_bundle(data: &str) -> Result<ShareBundle, String> {
+    let bundle: ShareBundle = serde_json::from_str(data).map_err(|_| t!("not-a-bundle"))?;
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(t!("not-a-bundle"));
+    }
+    if bundle.version > BUNDLE_VERSION {
+        return Err(t!("bundle-version-too-new", version = bundle.version));
+    }
+    Ok(bundle)
+}
+
+// 有接收人时加密整个清单；包含文件密钥的清单不允许以明文发布
+pub fn encode_bundle(bundle: &ShareBundle, recipients: &[String]) -> Result<String, String> {
+    let data = serde_json::to_string(bundle)
+        .map_err(|e| t!("serialize-bundle-failed", error = e.to_string()))?;
+    if recipients.is_empty() {
+        if bundle.has_file_keys() {
+            return Err(t!("encrypted-bundle-needs-recipients"));
+        }
+        return Ok(data);
+    }
+    let sealed = SealedBundle {
+        format: SEALED_BUNDLE_FORMAT.into(),
+        version: BUNDLE_VERSION,
+        payload: seal(data.as_bytes(), recipients)?,
+    };
+    serde_json::to_string(&sealed).map_err(|e| t!("serialize-bundle-failed", error = e.to_string()))
+}
+
+pub fn decode_bundle(data: &str, identity: Option<&Identity>) -> Result<ShareBundle, String> {
+    let Ok(sealed) = serde_json::from_str::<SealedBundle>(data) else {
+        return parse_bundle(data);
+    };
+    if sealed.format != SEALED_BUNDLE_FORMAT {
+        return parse_bundle(data);
+    }
+    let identity = identity.ok_or_else(|| t!("sealed-bundle-needs-identity"))?;
+    let plaintext = open(&sealed.payload, identity)?;
+    parse_bundle(&String::from_utf8(plaintext).map_err(|_| t!("bundle-corrupted"))?)
+}
+
+pub async fn publish_bundle(
+    walrus_api: &WalrusApi,
+    bundle: &ShareBundle,
+    recipients: &[String],
+    epochs: u32,
+) -> Result<String, String> {
+    let data = encode_bundle(bundle, recipients)?;
+    walrus_api.upload_config_data(data, epochs).await
+}
+
+pub async fn fetch_bundle(
+    walrus_api: &WalrusApi,
+    id: String,
+    identity: Option<Identity>,
+) -> Result<ShareBundle, String> {
+    let data = walrus_api.download_config_by_id(id).await?;
+    decode_bundle(&data, identity.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_round_trip() {
+        let files = vec![FileEntry {
+            id: "abc".into(),
+            name: "a.txt".into(),
+            size: Some(3),
+            sha256: Some("hash".into()),
+            tags: vec!["私人".into()],
+            ..Default::default()
+        }];
+        let bundle = ShareBundle::new(&files);
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(!json.contains("私人"));
+
+        let parsed = parse_bundle(&json).unwrap();
+        assert_eq!(parsed, bundle);
+        assert_eq!(parsed.total_size(), Some(3));
+        let entry = parsed.entries[0].to_file_entry();
+        assert_eq!((entry.id.as_str(), entry.size), ("abc", Some(3)));
+        assert!(entry.tags.is_empty());
+
+        assert!(parse_bundle("[]").is_err());
+        assert!(parse_bundle(&json.replace(BUNDLE_FORMAT, "other")).is_err());
+    }
+
+    #[test]
+    fn encrypted_entries_require_sealed_bundles() {
+        let files = vec![FileEntry {
+            id: "abc".into(),
+            name: "a.txt".into(),
+            file_key: Some("key".into()),
+            ..Default::default()
+        }];
+        let bundle = ShareBundle::new(&files);
+        assert!(encode_bundle(&bundle, &[]).is_err());
+
+        let (alice, bob) = (Identity::generate(), Identity::generate());
+        let sealed = encode_bundle(&bundle, std::slice::from_ref(&alice.public_key)).unwrap();
+        assert!(!sealed.contains("a.txt"));
+        assert_eq!(decode_bundle(&sealed, Some(&alice)).unwrap(), bundle);
+        assert!(decode_bundle(&sealed, Some(&bob)).is_err());
+        assert!(decode_bundle(&sealed, None).is_err());
+        assert_eq!(
+            bundle.entries[0].to_file_entry().file_key.as_deref(),
+            Some("key")
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::Message;
+use crate::i18n::t;
+use iced::event::Status;
+use iced::keyboard::{Event as KeyboardEvent, Key, Modifiers, key::Named};
+use iced::widget::text_input;
+
+// 命令面板中可执行的操作，每一项都对应一个已有的消息
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    ChooseFiles,
+    UploadAll,
+    FocusSearch,
+    SelectAll,
+    InvertSelection,
+    DeleteSelection,
+    DownloadSelection,
+    ShareSelection,
+    ToggleGridView,
+    DownloadById,
+    OpenBundle,
+    ImportConfig,
+    ExportConfig,
+    UploadConfig,
+    LoadConfig,
+    UndoImport,
+    ConfigHistory,
+    SyncPanel,
+    SyncNow,
+    Workspaces,
+    IdentityKeys,
+    Trash,
+    ActivityLog,
+}
+
+impl PaletteCommand {
+    pub const ALL: [PaletteCommand; 23] = [
+        PaletteCommand::ChooseFiles,
+        PaletteCommand::UploadAll,
+        PaletteCommand::FocusSearch,
+        PaletteCommand::SelectAll,
+        PaletteCommand::InvertSelection,
+        PaletteCommand::DeleteSelection,
+        PaletteCommand::DownloadSelection,
+        PaletteCommand::ShareSelection,
+        PaletteCommand::ToggleGridView,
+        PaletteCommand::DownloadById,
+        PaletteCommand::OpenBundle,
+        PaletteCommand::ImportConfig,
+        PaletteCommand::ExportConfig,
+        PaletteCommand::UploadConfig,
+        PaletteCommand::LoadConfig,
+        PaletteCommand::UndoImport,
+        PaletteCommand::ConfigHistory,
+        PaletteCommand::SyncPanel,
+        PaletteCommand::SyncNow,
+        PaletteCommand::Workspaces,
+        PaletteCommand::IdentityKeys,
+        PaletteCommand::Trash,
+        PaletteCommand::ActivityLog,
+    ];
+
+    // 英文标识，同时作为消息目录的键后缀，任何界面语言下都可以用它搜索
+    fn id(self) -> &'static str {
+        match self {
+            PaletteCommand::ChooseFiles => "choose-files",
+            PaletteCommand::UploadAll => "upload-all",
+            PaletteCommand::FocusSearch => "focus-search",
+            PaletteCommand::SelectAll => "select-all",
+            PaletteCommand::InvertSelection => "invert-selection",
+            PaletteCommand::DeleteSelection => "delete-selection",
+            PaletteCommand::DownloadSelection => "download-selection",
+            PaletteCommand::ShareSelection => "share-selection",
+            PaletteCommand::ToggleGridView => "toggle-grid-view",
+            PaletteCommand::DownloadById => "download-by-id",
+            PaletteCommand::OpenBundle => "open-bundle",
+            PaletteCommand::ImportConfig => "import-config",
+            PaletteCommand::ExportConfig => "export-config",
+            PaletteCommand::UploadConfig => "upload-config",
+            PaletteCommand::LoadConfig => "load-config",
+            PaletteCommand::UndoImport => "undo-import",
+            PaletteCommand::ConfigHistory => "config-history",
+            PaletteCommand::SyncPanel => "sync-panel",
+            PaletteCommand::SyncNow => "sync-now",
+            PaletteCommand::Workspaces => "workspaces",
+            PaletteCommand::IdentityKeys => "identity-keys",
+            PaletteCommand::Trash => "trash",
+            PaletteCommand::ActivityLog => "activity-log",
+        }
+    }
+
+    pub fn label(self) -> String {
+        t!(&format!("command-{}", self.id()))
+    }
+
+    pub fn shortcut(self) -> Option<&'static str> {
+        match self {
+            PaletteCommand::ChooseFiles => Some("Ctrl+O"),
+            PaletteCommand::FocusSearch => Some("Ctrl+F"),
+            PaletteCommand::SelectAll => Some("Ctrl+A"),
+            PaletteCommand::DeleteSelection => Some("Delete"),
+            PaletteCommand::DownloadSelection => Some("Ctrl+Shift+D"),
+            _ => None,
+        }
+    }
+
+    pub fn message(self) -> Message {
+        match self {
+            PaletteCommand::ChooseFiles => Message::TriggerFileSelection,
+            PaletteCommand::UploadAll => Message::UploadButtonPressed,
+            PaletteCommand::FocusSearch => Message::FocusSearch,
+            PaletteCommand::SelectAll => Message::SelectAllVisibleToggled(true),
+            PaletteCommand::InvertSelection => Message::InvertSelection,
+            PaletteCommand::DeleteSelection => Message::BatchDeleteButtonPressed,
+            PaletteCommand::DownloadSelection => Message::BatchDownloadButtonPressed,
+            PaletteCommand::ShareSelection => Message::ShareSelectionPressed,
+            PaletteCommand::ToggleGridView => Message::ToggleGridView,
+            PaletteCommand::DownloadById => Message::DownloadFromInputButtonPressed,
+            PaletteCommand::OpenBundle => Message::OpenBundlePressed,
+            PaletteCommand::ImportConfig => Message::TriggerImportConfig,
+            PaletteCommand::ExportConfig => Message::TriggerExportConfig,
+            PaletteCommand::UploadConfig => Message::UploadConfigButtonPressed,
+            PaletteCommand::LoadConfig => Message::LoadConfigFromIdButtonPressed,
+            PaletteCommand::UndoImport => Message::UndoLastImport,
+            PaletteCommand::ConfigHistory => Message::ToggleConfigHistory,
+            PaletteCommand::SyncPanel => Message::ToggleSyncPanel,
+            PaletteCommand::SyncNow => Message::SyncNowPressed,
+            PaletteCommand::Workspaces => Message::ToggleWorkspacePanel,
+            PaletteCommand::IdentityKeys => Message::ToggleIdentityPanel,
+            PaletteCommand::Trash => Message::ToggleTrashPanel,
+            PaletteCommand::ActivityLog => Message::ToggleActivityPanel,
+        }
+    }
+}
+
+// 模糊匹配：查询中的字符按顺序出现在候选文本中即可匹配，
+// 连续匹配和词首匹配得分更高；不匹配时返回 None
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+    for wanted in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = position + candidate[position..].iter().position(|c| *c == wanted)?;
+        score += 1;
+        if index == 0 || matches!(candidate[index - 1], ' ' | '-' | '_') {
+            score += 3;
+        }
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 2;
+        }
+        previous = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+// 按得分从高到低列出匹配查询的命令，同时匹配当前语言的名称和英文标识
+pub fn matching_commands(query: &str) -> Vec<PaletteCommand> {
+    let mut matches: Vec<(u32, PaletteCommand)> = PaletteCommand::ALL
+        .iter()
+        .filter_map(|command| {
+            let label = fuzzy_score(query, &command.label());
+            let id = fuzzy_score(query, command.id());
+            label.max(id).map(|score| (score, *command))
+        })
+        .collect();
+    matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    matches.into_iter().map(|(_, command)| command).collect()
+}
+
+pub fn search_input_id() -> text_input::Id {
+    text_input::Id::new("file-search")
+}
+
+pub fn palette_input_id() -> text_input::Id {
+    text_input::Id::new("command-palette")
+}
+
+// 全局快捷键；输入框获得焦点时按键已被输入框处理（例如 Ctrl+A 全选文字、Delete 删除字符），
+// 不会再触发快捷键。Esc 例外，输入框会先失去焦点，这里仍用它关闭命令面板。
+// 快捷键对应的命令包装为 Message::Shortcut，打开对话框或面板时由 update 丢弃
+pub fn shortcut_message(event: iced::Event, status: Status) -> Option<Message> {
+    let (key, modifiers) = match event {
+        iced::Event::Keyboard(KeyboardEvent::KeyPressed { key, modifiers, .. }) => (key, modifiers),
+        iced::Event::Keyboard(KeyboardEvent::ModifiersChanged(modifiers)) => {
+            return Some(Message::ModifiersChanged(modifiers));
+        }
+        _ => return None,
+    };
+    if key == Key::Named(Named::Escape) {
+        return Some(Message::CloseCommandPalette);
+    }
+    if status == Status::Captured {
+        return None;
+    }
+    if let Some(command) = shortcut_for(&key, modifiers) {
+        return Some(Message::Shortcut(command));
+    }
+    // Ctrl+K 或 Ctrl+Shift+P 打开命令面板
+    let Key::Character(c) = key.as_ref() else {
+        return None;
+    };
+    let palette_key = match c.to_lowercase().as_str() {
+        "k" => !modifiers.shift(),
+        "p" => modifiers.shift(),
+        _ => false,
+    };
+    (modifiers.command() && palette_key).then_some(Message::ToggleCommandPalette)
+}
+
+fn shortcut_for(key: &Key, modifiers: Modifiers) -> Option<PaletteCommand> {
+    if *key == Key::Named(Named::Delete) && modifiers.is_empty() {
+        return Some(PaletteCommand::DeleteSelection);
+    }
+    if !modifiers.command() {
+        return None;
+    }
+    let Key::Character(c) = key.as_ref() else {
+        return None;
+    };
+    // 按住 Shift 时部分平台报告大写字母
+    match (c.to_lowercase().as_str(), modifiers.shift()) {
+        ("o", false) => Some(PaletteCommand::ChooseFiles),
+        ("f", false) => Some(PaletteCommand::FocusSearch),
+        ("a", false) => Some(PaletteCommand::SelectAll),
+        ("d", true) => Some(PaletteCommand::DownloadSelection),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_matching_prefers_word_starts() {
+        assert!(fuzzy_score("dls", "download-selection").is_some());
+        assert!(fuzzy_score("xyz", "download-selection").is_none());
+        assert!(fuzzy_score("sld", "download-selection").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(
+            fuzzy_score("up", "upload-all").unwrap() > fuzzy_score("up", "group-panel").unwrap()
+        );
+
+        assert_eq!(
+            matching_commands("download sel").first(),
+            Some(&PaletteCommand::DownloadSelection)
+        );
+        assert_eq!(matching_commands("").len(), PaletteCommand::ALL.len());
+
+        let ctrl = Modifiers::COMMAND;
+        assert_eq!(
+            shortcut_for(&Key::Character("D".into()), ctrl | Modifiers::SHIFT),
+            Some(PaletteCommand::DownloadSelection)
+        );
+        assert_eq!(shortcut_for(&Key::Character("d".into()), ctrl), None);
+        assert_eq!(
+            shortcut_for(&Key::Named(Named::Delete), Modifiers::empty()),
+            Some(PaletteCommand::DeleteSelection)
+        );
+    }
+}
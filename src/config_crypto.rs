@@ -0,0 +1,167 @@
+use crate::i18n::t;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+
+// 加密配置的格式标识，用于和旧版明文配置区分
+const ENCRYPTED_CONFIG_FORMAT: &str = "walrus_store_encrypted_config";
+const ENCRYPTED_CONFIG_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+// 参数来自远程配置，可能由他人构造；超过上限时拒绝，避免占用大量内存或长时间计算
+const MAX_M_COST: u32 = 256 * 1024; // KiB，即 256 MiB
+const MAX_T_COST: u32 = 10;
+const MAX_P_COST: u32 = 4;
+
+// 上传到 Walrus 的加密配置信封
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedConfig {
+    format: String,
+    version: u32,
+    kdf: KdfParams,
+    nonce: String,      // Base64
+    ciphertext: String, // Base64
+}
+
+// Argon2id 参数随密文一起保存，以便以后调整参数时旧配置仍可解密
+#[derive(Debug, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    salt: String, // Base64
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+fn derive_key(passphrase: &str, kdf: &KdfParams) -> Result<[u8; KEY_LEN], String> {
+    if kdf.algorithm != "argon2id" {
+        return Err(t!("unsupported-kdf", algorithm = kdf.algorithm.as_str()));
+    }
+    if kdf.m_cost > MAX_M_COST || kdf.t_cost > MAX_T_COST || kdf.p_cost > MAX_P_COST {
+        return Err(t!(
+            "kdf-params-too-expensive",
+            m_cost = kdf.m_cost,
+            t_cost = kdf.t_cost,
+            p_cost = kdf.p_cost
+        ));
+    }
+    let salt = BASE64
+        .decode(&kdf.salt)
+        .map_err(|e| t!("decode-salt-failed", error = e.to_string()))?;
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(KEY_LEN))
+        .map_err(|e| t!("invalid-kdf-params", error = e.to_string()))?;
+    let mut key = [0u8; KEY_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+        .map_err(|e| t!("derive-key-failed", error = e.to_string()))?;
+    Ok(key)
+}
+
+// 判断配置数据是否为加密格式；旧版配置是明文 JSON 数组
+pub fn is_encrypted_config(data: &str) -> bool {
+    serde_json::from_str::<EncryptedConfig>(data)
+        .map(|config| config.format == ENCRYPTED_CONFIG_FORMAT)
+        .unwrap_or(false)
+}
+
+pub fn encrypt_config(plaintext: &str, passphrase: &str) -> Result<String, String> {
+    if passphrase.is_empty() {
+        return Err(t!("empty-config-passphrase"));
+    }
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let defaults = Params::default();
+    let kdf = KdfParams {
+        algorithm: "argon2id".into(),
+        salt: BASE64.encode(salt),
+        m_cost: defaults.m_cost(),
+        t_cost: defaults.t_cost(),
+        p_cost: defaults.p_cost(),
+    };
+    let key = derive_key(passphrase, &kdf)?;
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|e| t!("encrypt-config-failed", error = e.to_string()))?;
+
+    let envelope = EncryptedConfig {
+        format: ENCRYPTED_CONFIG_FORMAT.into(),
+        version: ENCRYPTED_CONFIG_VERSION,
+        kdf,
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    };
+    serde_json::to_string_pretty(&envelope)
+        .map_err(|e| t!("serialize-encrypted-config-failed", error = e.to_string()))
+}
+
+pub fn decrypt_config(data: &str, passphrase: &str) -> Result<String, String> {
+    let envelope: EncryptedConfig = serde_json::from_str(data)
+        .map_err(|e| t!("parse-encrypted-config-failed", error = e.to_string()))?;
+    if envelope.format != ENCRYPTED_CONFIG_FORMAT {
+        return Err(t!("not-an-encrypted-config"));
+    }
+    if envelope.version > ENCRYPTED_CONFIG_VERSION {
+        return Err(t!("unsupported-config-version", version = envelope.version));
+    }
+    let nonce = BASE64
+        .decode(&envelope.nonce)
+        .map_err(|e| t!("decode-nonce-failed", error = e.to_string()))?;
+    if nonce.len() != NONCE_LEN {
+        return Err(t!("invalid-nonce-length"));
+    }
+    let ciphertext = BASE64
+        .decode(&envelope.ciphertext)
+        .map_err(|e| t!("decode-ciphertext-failed", error = e.to_string()))?;
+    let key = derive_key(passphrase, &envelope.kdf)?;
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let plaintext = cipher
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| t!("wrong-passphrase"))?;
+    String::from_utf8(plaintext).map_err(|e| t!("config-not-utf8", error = e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_config_round_trip() {
+        let plaintext = r#"[{"id":"abc","name":"a.txt","uploaded_at":"2024-01-01 00:00:00"}]"#;
+        let encrypted = encrypt_config(plaintext, "correct horse").unwrap();
+
+        assert!(is_encrypted_config(&encrypted));
+        assert!(!encrypted.contains("a.txt"));
+        assert_eq!(
+            decrypt_config(&encrypted, "correct horse").unwrap(),
+            plaintext
+        );
+        assert!(decrypt_config(&encrypted, "wrong").is_err());
+    }
+
+    #[test]
+    fn expensive_kdf_params_are_rejected() {
+        let encrypted = encrypt_config("[]", "pass").unwrap();
+        let mut config: serde_json::Value = serde_json::from_str(&encrypted).unwrap();
+        config["kdf"]["m_cost"] = (4 * 1024 * 1024).into();
+        let hostile = config.to_string();
+        assert!(decrypt_config(&hostile, "pass").is_err());
+        config["kdf"]["m_cost"] = MAX_M_COST.into();
+        config["kdf"]["t_cost"] = 1000.into();
+        assert!(decrypt_config(&config.to_string(), "pass").is_err());
+    }
+
+    #[test]
+    fn legacy_plaintext_config_is_not_encrypted() {
+        assert!(!is_encrypted_config("[]"));
+        assert!(!is_encrypted_config("not json"));
+    }
+}
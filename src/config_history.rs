@@ -0,0 +1,131 @@
+use crate::config_crypto::{decrypt_config, encrypt_config, is_encrypted_config};
+use crate::data::FileEntry;
+use crate::i18n::t;
+use crate::walrus_api::WalrusApi;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+// 上传到 Walrus 的配置快照内容（加密前），通过 previous 链接到上一个快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    pub previous: Option<String>, // 上一个快照的 blob ID
+    pub created_at: String,
+    pub files: Vec<FileEntry>,
+}
+
+impl ConfigSnapshot {
+    pub fn new(files: Vec<FileEntry>, previous: Option<String>) -> Self {
+        Self {
+            previous,
+            created_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            files,
+        }
+    }
+}
+
+// 本地记录的已发布快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotRecord {
+    pub id: String,
+    pub created_at: String,
+    pub entry_count: usize,
+    pub previous: Option<String>,
+    #[serde(default = "legacy_snapshot_epochs")]
+    pub epochs: u32, // 快照在 Walrus 上的存储周期数
+}
+
+// 旧版本发布的快照固定只存储 1 个周期
+fn legacy_snapshot_epochs() -> u32 {
+    1
+}
+
+// 查看快照时要执行的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotAction {
+    Diff,    // 与当前文件列表对比
+    Restore, // 通过导入预览恢复
+}
+
+// 解析远程配置内容；旧版配置是文件记录的 JSON 数组，没有快照信息
+pub fn parse_config_payload(data: &str) -> Result<ConfigSnapshot, String> {
+    if let Ok(snapshot) = serde_json::from_str::<ConfigSnapshot>(data) {
+        return Ok(snapshot);
+    }
+    serde_json::from_str::<Vec<FileEntry>>(data)
+        .map(|files| ConfigSnapshot {
+            previous: None,
+            created_at: String::new(),
+            files,
+        })
+        .map_err(|e| t!("parse-config-failed", error = e.to_string()))
+}
+
+// 加密并上传一个配置快照，返回用于本地历史的记录
+pub async fn publish_snapshot(
+    walrus_api: &WalrusApi,
+    snapshot: ConfigSnapshot,
+    passphrase: String,
+    epochs: u32,
+) -> Result<SnapshotRecord, String> {
+    let config_json = serde_json::to_string_pretty(&snapshot)
+        .map_err(|e| t!("serialize-config-failed", error = e.to_string()))?;
+    let encrypted = encrypt_config(&config_json, &passphrase)?;
+    let id = walrus_api.upload_config_data(encrypted, epochs).await?;
+    Ok(SnapshotRecord {
+        id,
+        created_at: snapshot.created_at,
+        entry_count: snapshot.files.len(),
+        previous: snapshot.previous,
+        epochs,
+    })
+}
+
+// 下载并（如有需要）解密一个配置快照
+pub async fn fetch_snapshot(
+    walrus_api: &WalrusApi,
+    blob_id: String,
+    passphrase: String,
+) -> Result<ConfigSnapshot, String> {
+    let data = walrus_api.download_config_by_id(blob_id).await?;
+    if is_encrypted_config(&data) {
+        if passphrase.is_empty() {
+            return Err(t!("snapshot-is-encrypted"));
+        }
+        parse_config_payload(&decrypt_config(&data, &passphrase)?)
+    } else {
+        parse_config_payload(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_snapshot_and_legacy_payloads() {
+        let files = vec![FileEntry {
+            id: "abc".into(),
+            name: "a.txt".into(),
+            uploaded_at: "2024-01-01 00:00:00".into(),
+            ..Default::default()
+        }];
+        let snapshot = ConfigSnapshot::new(files.clone(), Some("prev".into()));
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(parse_config_payload(&json).unwrap(), snapshot);
+
+        let legacy = serde_json::to_string(&files).unwrap();
+        let parsed = parse_config_payload(&legacy).unwrap();
+        assert_eq!(parsed.files, files);
+        assert_eq!(parsed.previous, None);
+
+        assert!(parse_config_payload("{}").is_err());
+    }
+
+    #[test]
+    fn legacy_records_default_to_one_epoch() {
+        let json =
+            r#"{"id":"abc","created_at":"2024-01-01 00:00:00","entry_count":2,"previous":null}"#;
+        let record: SnapshotRecord = serde_json::from_str(json).unwrap();
+        assert_eq!(record.epochs, 1);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::data::FileEntry;
+use crate::i18n::t;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// 导入配置时的合并模式，按 blob ID 匹配记录
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportMode {
+    Replace, // 用导入的列表整体替换本地列表，需要明确选择
+    #[default]
+    Merge, // 保留本地记录，仅追加本地没有的记录（默认）
+    MergeKeepNewer, // 合并，ID 冲突时保留上传时间较新的记录
+}
+
+impl ImportMode {
+    pub const ALL: [ImportMode; 3] = [
+        ImportMode::Replace,
+        ImportMode::Merge,
+        ImportMode::MergeKeepNewer,
+    ];
+}
+
+impl fmt::Display for ImportMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            ImportMode::Replace => "import-mode-replace",
+            ImportMode::Merge => "import-mode-merge",
+            ImportMode::MergeKeepNewer => "import-mode-merge-keep-newer",
+        };
+        write!(f, "{}", t!(key))
+    }
+}
+
+// 同一 ID 在本地和导入列表中内容不同的记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportConflict {
+    pub local: FileEntry,
+    pub incoming: FileEntry,
+    pub keep_incoming: bool, // 按当前模式应用后是否采用导入的记录
+}
+
+// 应用导入前展示给用户的变更预览
+#[derive(Debug, Clone, Default)]
+pub struct ImportPreview {
+    pub added: Vec<FileEntry>,
+    pub removed: Vec<FileEntry>,
+    pub conflicts: Vec<ImportConflict>,
+    pub unchanged: usize,
+}
+
+// 等待用户确认的导入
+#[derive(Debug, Clone)]
+pub struct PendingImport {
+    pub source: String, // 导入来源描述（文件路径或配置 ID）
+    pub incoming: Vec<FileEntry>,
+    pub mode: ImportMode, // 本次导入使用的模式
+}
+
+// 去掉导入列表中重复的 ID，只保留第一次出现的记录
+fn dedup_by_id(entries: &[FileEntry]) -> Vec<FileEntry> {
+    let mut seen = HashSet::new();
+    entries
+        .iter()
+        .filter(|entry| seen.insert(entry.id.clone()))
+        .cloned()
+        .collect()
+}
+
+fn incoming_is_newer(local: &FileEntry, incoming: &FileEntry) -> bool {
+    // uploaded_at 使用 "%Y-%m-%d %H:%M:%S" 格式，可直接按字符串比较
+    incoming.uploaded_at > local.uploaded_at
+}
+
+pub fn preview_import(
+    local: &[FileEntry],
+    incoming: &[FileEntry],
+    mode: ImportMode,
+) -> ImportPreview {
+    let incoming = dedup_by_id(incoming);
+    let local_by_id: HashMap<&str, &FileEntry> = local
+        .iter()
+        .map(|entry| (entry.id.as_str(), entry))
+        .collect();
+    let incoming_ids: HashSet<&str> = incoming.iter().map(|entry| entry.id.as_str()).collect();
+
+    let mut preview = ImportPreview::default();
+    for entry in &incoming {
+        match local_by_id.get(entry.id.as_str()) {
+            None => preview.added.push(entry.clone()),
+            Some(local_entry) if *local_entry == entry => preview.unchanged += 1,
+            Some(local_entry) => preview.conflicts.push(ImportConflict {
+                local: (*local_entry).clone(),
+                incoming: entry.clone(),
+                keep_incoming: match mode {
+                    ImportMode::Replace => true,
+                    ImportMode::Merge => false,
+                    ImportMode::MergeKeepNewer => incoming_is_newer(local_entry, entry),
+                },
+            }),
+        }
+    }
+    if mode == ImportMode::Replace {
+        preview.removed = local
+            .iter()
+            .filter(|entry| !incoming_ids.contains(entry.id.as_str()))
+            .cloned()
+            .collect();
+    }
+    preview
+}
+
+pub fn apply_import(
+    local: &[FileEntry],
+    incoming: &[FileEntry],
+    mode: ImportMode,
+) -> Vec<FileEntry> {
+    let incoming = dedup_by_id(incoming);
+    if mode == ImportMode::Replace {
+        return incoming;
+    }
+
+    let incoming_by_id: HashMap<&str, &FileEntry> = incoming
+        .iter()
+        .map(|entry| (entry.id.as_str(), entry))
+        .collect();
+    let mut local_ids = HashSet::new();
+    let mut merged: Vec<FileEntry> = local
+        .iter()
+        .map(|entry| {
+            local_ids.insert(entry.id.as_str());
+            match incoming_by_id.get(entry.id.as_str()) {
+                Some(other)
+                    if mode == ImportMode::MergeKeepNewer && incoming_is_newer(entry, other) =>
+                {
+                    (*other).clone()
+                }
+                _ => entry.clone(),
+            }
+        })
+        .collect();
+    merged.extend(
+        incoming
+            .iter()
+            .filter(|entry| !local_ids.contains(entry.id.as_str()))
+            .cloned(),
+    );
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, name: &str, uploaded_at: &str) -> FileEntry {
+        FileEntry {
+            id: id.to_string(),
+            name: name.to_string(),
+            uploaded_at: uploaded_at.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merge_keeps_local_entries_and_appends_new_ones() {
+        let local = vec![
+            entry("a", "mine.txt", "2024-01-01 00:00:00"),
+            entry("b", "old.txt", "2024-01-01 00:00:00"),
+        ];
+        let incoming = vec![
+            entry("b", "renamed.txt", "2024-02-01 00:00:00"),
+            entry("c", "theirs.txt", "2024-01-05 00:00:00"),
+        ];
+
+        let merged = apply_import(&local, &incoming, ImportMode::Merge);
+        assert_eq!(
+            merged,
+            vec![local[0].clone(), local[1].clone(), incoming[1].clone()]
+        );
+
+        let newer = apply_import(&local, &incoming, ImportMode::MergeKeepNewer);
+        assert_eq!(
+            newer,
+            vec![local[0].clone(), incoming[0].clone(), incoming[1].clone()]
+        );
+
+        let replaced = apply_import(&local, &incoming, ImportMode::Replace);
+        assert_eq!(replaced, incoming);
+    }
+
+    #[test]
+    fn preview_reports_added_removed_and_conflicts() {
+        let local = vec![
+            entry("a", "mine.txt", "2024-01-01 00:00:00"),
+            entry("b", "old.txt", "2024-03-01 00:00:00"),
+            entry("d", "same.txt", "2024-01-01 00:00:00"),
+        ];
+        let incoming = vec![
+            entry("b", "renamed.txt", "2024-02-01 00:00:00"),
+            entry("c", "theirs.txt", "2024-01-05 00:00:00"),
+            entry("c", "duplicate.txt", "2024-01-06 00:00:00"),
+            entry("d", "same.txt", "2024-01-01 00:00:00"),
+        ];
+
+        let preview = preview_import(&local, &incoming, ImportMode::Replace);
+        assert_eq!(preview.added, vec![incoming[1].clone()]);
+        assert_eq!(preview.removed, vec![local[0].clone()]);
+        assert_eq!(preview.conflicts.len(), 1);
+        assert!(preview.conflicts[0].keep_incoming);
+        assert_eq!(preview.unchanged, 1);
+
+        let preview = preview_import(&local, &incoming, ImportMode::MergeKeepNewer);
+        assert!(preview.removed.is_empty());
+        assert!(!preview.conflicts[0].keep_incoming);
+    }
+}
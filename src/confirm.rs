@@ -0,0 +1,51 @@
+use crate::i18n::t;
+
+// 需要用户确认后才执行的破坏性操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfirmAction {
+    DeleteEntries(Vec<String>),                    // 把文件记录移到回收站
+    DeleteFromWalrus(String),                      // 从 Walrus 删除可删除的 blob，并移除记录
+    ReplaceImport { removed: usize },              // 以替换模式应用导入，会移除本地记录
+    PurgeTrash(Vec<String>),                       // 从回收站永久删除记录
+    DeleteWorkspace(String),                       // 删除工作区及其全部数据
+    ShortenRetention { days: u32, purged: usize }, // 缩短回收站保留期，会清除已过期的记录
+}
+
+impl ConfirmAction {
+    // 对话框中的说明文字
+    pub fn prompt(&self, retention_days: u32) -> String {
+        match self {
+            ConfirmAction::DeleteEntries(ids) if retention_days == 0 => {
+                t!("confirm-delete-entries-kept", count = ids.len())
+            }
+            ConfirmAction::DeleteEntries(ids) => t!(
+                "confirm-delete-entries",
+                count = ids.len(),
+                days = retention_days
+            ),
+            ConfirmAction::DeleteFromWalrus(_) => t!("confirm-delete-from-walrus"),
+            ConfirmAction::ReplaceImport { removed } => {
+                t!("confirm-replace-import", count = *removed)
+            }
+            ConfirmAction::PurgeTrash(ids) => t!("confirm-purge-trash", count = ids.len()),
+            ConfirmAction::DeleteWorkspace(name) => {
+                t!("confirm-delete-workspace", name = name.as_str())
+            }
+            ConfirmAction::ShortenRetention { days, purged } => {
+                t!("confirm-shorten-retention", days = *days, count = *purged)
+            }
+        }
+    }
+
+    // 确认按钮的文字
+    pub fn confirm_label(&self) -> String {
+        match self {
+            ConfirmAction::DeleteEntries(_) => t!("move-to-trash"),
+            ConfirmAction::DeleteFromWalrus(_) => t!("delete-from-walrus"),
+            ConfirmAction::ReplaceImport { .. } => t!("replace-list"),
+            ConfirmAction::PurgeTrash(_) => t!("delete-permanently"),
+            ConfirmAction::DeleteWorkspace(_) => t!("delete-workspace"),
+            ConfirmAction::ShortenRetention { .. } => t!("shorten-retention"),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::blob_delete::WalrusCli;
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+// 1 WAL = 10^9 FROST，价格和费用都以 FROST 计
+pub const FROST_PER_WAL: u64 = 1_000_000_000;
+
+// 计算费用需要的网络信息：分片数决定编码后的大小，价格按存储单元（通常为 1 MiB）计
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceInfo {
+    pub n_shards: u64,
+    pub storage_unit_size: u64,
+    pub storage_price_per_unit: u64, // 每个存储单元每个周期的存储价格
+    pub write_price_per_unit: u64,   // 每个存储单元一次性的写入价格
+}
+
+// 价格信息的获取状态
+#[derive(Debug, Clone, Default)]
+pub enum PriceStatus {
+    #[default]
+    Unknown,
+    Loading,
+    Ready(PriceInfo),
+    Failed(String),
+}
+
+// 价格来源；实际使用 walrus 命令行客户端查询网络，测试中使用固定价格
+pub trait PriceSource {
+    fn price_info(&self) -> impl Future<Output = Result<PriceInfo, String>> + Send;
+}
+
+// `walrus info --json` 输出中用到的部分
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InfoOutput {
+    storage_info: StorageInfo,
+    size_info: SizeInfo,
+    price_info: InfoPrices,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StorageInfo {
+    n_shards: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SizeInfo {
+    storage_unit_size: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InfoPrices {
+    storage_price_per_unit_size: u64,
+    write_price_per_unit_size: u64,
+}
+
+pub fn parse_info(json: &str) -> Result<PriceInfo, String> {
+    let info: InfoOutput = serde_json::from_str(json)
+        .map_err(|e| t!("parse-price-info-failed", error = e.to_string()))?;
+    if info.storage_info.n_shards == 0 || info.size_info.storage_unit_size == 0 {
+        return Err(t!(
+            "parse-price-info-failed",
+            error = "n_shards / storage_unit_size = 0"
+        ));
+    }
+    Ok(PriceInfo {
+        n_shards: info.storage_info.n_shards,
+        storage_unit_size: info.size_info.storage_unit_size,
+        storage_price_per_unit: info.price_info.storage_price_per_unit_size,
+        write_price_per_unit: info.price_info.write_price_per_unit_size,
+    })
+}
+
+impl PriceSource for WalrusCli {
+    async fn price_info(&self) -> Result<PriceInfo, String> {
+        parse_info(&self.run(&["info", "--json"]).await?)
+    }
+}
+
+// 编码后的大小（RedStuff 编码）：每个分片保存一个主切片和一个次切片，
+// 另外每个分片都保存一份元数据（每个分片两个 32 字节哈希加 32 字节 blob ID）
+pub fn encoded_size(n_shards: u64, unencoded_size: u64) -> u64 {
+    let max_faulty = (n_shards - 1) / 3;
+    let primary = n_shards - 2 * max_faulty;
+    let secondary = n_shards - max_faulty;
+    let symbol_size = unencoded_size.div_ceil(primary * secondary).max(1);
+    // Reed-Solomon 编码要求符号大小为偶数
+    let symbol_size = symbol_size.next_multiple_of(2);
+    let sliver_pair_size = (primary + secondary) * symbol_size;
+    let metadata_size = n_shards * 32 * 2 + 32;
+    n_shards * (sliver_pair_size + metadata_size)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    pub encoded_size: u64,
+    pub frost: u64,
+}
+
+// 费用 = 存储单元数 × (存储价格 × 周期数 + 写入价格)
+pub fn estimate(info: &PriceInfo, unencoded_size: u64, epochs: u32) -> CostEstimate {
+    let encoded_size = encoded_size(info.n_shards, unencoded_size);
+    let units = encoded_size.div_ceil(info.storage_unit_size);
+    let per_unit = info.storage_price_per_unit * u64::from(epochs) + info.write_price_per_unit;
+    CostEstimate {
+        encoded_size,
+        frost: units * per_unit,
+    }
+}
+
+pub fn format_wal(frost: u64) -> String {
+    format!("{:.4} WAL", frost as f64 / FROST_PER_WAL as f64)
+}
+
+// 每个工作区累计的存储费用，保存在工作区目录的 spend.json 中
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpendLedger {
+    pub total_frost: u64,
+    pub uploads: u32, // 产生费用的上传次数；blob 已存在时不产生费用
+}
+
+impl SpendLedger {
+    pub fn record(&mut self, frost: u64) {
+        if frost > 0 {
+            self.total_frost += frost;
+            self.uploads += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 固定价格的离线替身
+    struct FixedPrices;
+
+    impl PriceSource for FixedPrices {
+        async fn price_info(&self) -> Result<PriceInfo, String> {
+            parse_info(
+                r#"{
+                    "epochInfo": {"currentEpoch": 7},
+                    "storageInfo": {"nNodes": 100, "nShards": 1000},
+                    "sizeInfo": {"storageUnitSize": 1048576, "maxBlobSize": 13958643712},
+                    "priceInfo": {"storagePricePerUnitSize": 100000, "writePricePerUnitSize": 20000}
+                }"#,
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn estimates_follow_size_epochs_and_prices() {
+        let info = FixedPrices.price_info().await.unwrap();
+        assert_eq!(info.n_shards, 1000);
+
+        // 1000 个分片：主切片 334 个符号，次切片 667 个符号
+        assert_eq!(encoded_size(1000, 0), 1000 * (1001 * 2 + 64_032));
+        assert_eq!(encoded_size(1000, 334 * 667 * 2), encoded_size(1000, 1));
+        assert!(encoded_size(1000, 334 * 667 * 2 + 1) > encoded_size(1000, 1));
+
+        let small = estimate(&info, 1024, 1);
+        assert_eq!(small.encoded_size, 66_034_000);
+        assert_eq!(small.frost, 63 * 120_000);
+        assert_eq!(estimate(&info, 1024, 3).frost, 63 * 320_000);
+        assert_eq!(format_wal(small.frost), "0.0076 WAL");
+
+        assert!(parse_info("{}").is_err());
+
+        let mut ledger = SpendLedger::default();
+        ledger.record(small.frost);
+        ledger.record(0);
+        assert_eq!(ledger.uploads, 1);
+        assert_eq!(ledger.total_frost, small.frost);
+    }
+}
@@ -1,11 +1,33 @@
+use crate::blob_envelope::BlobMetadata;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FileEntry {
     pub id: String,
     pub name: String,
     pub uploaded_at: String, // ISO 8601 格式
+    // 以下字段在上传时记录，旧版配置中的记录没有这些字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>, // 原始文件大小（字节）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>, // 原始内容的 SHA-256（十六进制）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epochs: Option<u32>, // 上传时预付的存储周期数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_key: Option<String>, // 加密上传时的文件密钥（Base64），下载时用于解密
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deletable: bool, // 以可删除方式存储，可以从 Walrus 删除
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>, // 上传时新建的 Sui blob 对象 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>, // blob 对象发送到的 Sui 地址，未设置时由发布器持有
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>, // 上传时实际支付的存储费用（FROST），blob 已存在时为 0
 }
 
 impl FileEntry {
@@ -14,6 +36,36 @@ impl FileEntry {
             id,
             name,
             uploaded_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            ..Default::default()
         }
     }
+
+    pub fn with_metadata(mut self, metadata: &BlobMetadata) -> Self {
+        self.size = Some(metadata.size);
+        self.mime = Some(metadata.mime.clone());
+        self.sha256 = Some(metadata.sha256.clone());
+        self
+    }
+
+    // 文件搜索：不区分大小写地匹配文件名、ID 或任一标签
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query)
+            || self.id.to_lowercase().contains(&query)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&query))
+    }
+}
+
+// 解析以逗号分隔的标签输入，去掉空白和重复项
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split([',', '，']).map(str::trim) {
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
 }
@@ -0,0 +1,295 @@
+use crate::blob_envelope::sha256_hex;
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+const MAX_RENAME_ATTEMPTS: u32 = 1000;
+const MAX_FILE_NAME_BYTES: usize = 255;
+const ILLEGAL_CHARS: &str = r#"<>:"/\|?*"#;
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+// 下载目标文件已存在时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    #[default]
+    Ask, // 暂存下载内容，由用户逐个决定
+    Overwrite,
+    Skip,
+    Rename,      // 自动在文件名后追加 (1)、(2) 等后缀
+    CompareHash, // 内容相同则跳过，不同则自动重命名
+}
+
+impl ConflictPolicy {
+    pub const ALL: [ConflictPolicy; 5] = [
+        ConflictPolicy::Ask,
+        ConflictPolicy::Overwrite,
+        ConflictPolicy::Skip,
+        ConflictPolicy::Rename,
+        ConflictPolicy::CompareHash,
+    ];
+
+    // 询问用户时可以选择的处理方式
+    pub const RESOLUTIONS: [ConflictPolicy; 4] = [
+        ConflictPolicy::Overwrite,
+        ConflictPolicy::Skip,
+        ConflictPolicy::Rename,
+        ConflictPolicy::CompareHash,
+    ];
+}
+
+impl fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            ConflictPolicy::Ask => "conflict-policy-ask",
+            ConflictPolicy::Overwrite => "conflict-policy-overwrite",
+            ConflictPolicy::Skip => "conflict-policy-skip",
+            ConflictPolicy::Rename => "conflict-policy-rename",
+            ConflictPolicy::CompareHash => "conflict-policy-compare-hash",
+        };
+        write!(f, "{}", t!(key))
+    }
+}
+
+// 等待用户决定的下载冲突，下载的内容暂存在内存中
+#[derive(Clone)]
+pub struct PendingConflict {
+    pub path: PathBuf,
+    pub content: Vec<u8>,
+}
+
+impl fmt::Debug for PendingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingConflict")
+            .field("path", &self.path)
+            .field("size", &self.content.len())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DownloadOutcome {
+    Saved(PathBuf),
+    Skipped(PathBuf),
+    Identical(PathBuf), // 已存在内容相同的文件
+    Conflict(PendingConflict),
+}
+
+impl fmt::Display for DownloadOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (key, path) = match self {
+            DownloadOutcome::Saved(path) => ("download-saved", path),
+            DownloadOutcome::Skipped(path) => ("download-skipped", path),
+            DownloadOutcome::Identical(path) => ("download-identical", path),
+            DownloadOutcome::Conflict(conflict) => ("download-exists", &conflict.path),
+        };
+        write!(f, "{}", t!(key, path = path.display().to_string()))
+    }
+}
+
+// 文件名来自导入或远程配置，可能由他人控制；写入磁盘前统一清理：
+// 只保留最后一段路径，替换非法字符和控制字符，避开 Windows 保留名和隐藏文件，
+// 并限制长度（保留扩展名）。清理后为空的名称直接拒绝
+pub fn sanitize_file_name(name: &str) -> Result<String, String> {
+    let last_component = name
+        .split(['/', '\\'])
+        .map(str::trim)
+        .rfind(|part| !part.is_empty() && *part != "." && *part != "..")
+        .ok_or_else(|| t!("invalid-file-name", name = format!("{:?}", name)))?;
+
+    let mut sanitized: String = last_component
+        .chars()
+        .map(|c| {
+            if c.is_control() || ILLEGAL_CHARS.contains(c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    // Windows 会去掉结尾的点和空格
+    sanitized = sanitized.trim_end_matches(['.', ' ']).to_string();
+    if sanitized.is_empty() {
+        return Err(t!("invalid-file-name", name = format!("{:?}", name)));
+    }
+    if sanitized.starts_with('.') {
+        sanitized.replace_range(..1, "_");
+    }
+    let stem = sanitized.split('.').next().unwrap_or_default();
+    if RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem.trim_end()))
+    {
+        sanitized.insert(0, '_');
+    }
+    Ok(truncate_file_name(&sanitized))
+}
+
+// 超长的文件名截断主干部分，尽量保留扩展名
+fn truncate_file_name(name: &str) -> String {
+    if name.len() <= MAX_FILE_NAME_BYTES {
+        return name.to_string();
+    }
+    let (stem, extension) = match name.rfind('.') {
+        Some(index) if index > 0 && name.len() - index <= 16 => name.split_at(index),
+        _ => (name, ""),
+    };
+    let mut end = MAX_FILE_NAME_BYTES - extension.len();
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &stem[..end], extension)
+}
+
+// 按冲突处理方式把下载内容写到 path
+pub fn save_download(
+    path: PathBuf,
+    content: Vec<u8>,
+    policy: ConflictPolicy,
+) -> Result<DownloadOutcome, String> {
+    match policy {
+        ConflictPolicy::Overwrite => {
+            fs::write(&path, content).map_err(|e| e.to_string())?;
+            Ok(DownloadOutcome::Saved(path))
+        }
+        ConflictPolicy::CompareHash if path.exists() => {
+            let existing = fs::read(&path).map_err(|e| e.to_string())?;
+            if sha256_hex(&existing) == sha256_hex(&content) {
+                Ok(DownloadOutcome::Identical(path))
+            } else {
+                write_renamed(&path, &content)
+            }
+        }
+        ConflictPolicy::Rename | ConflictPolicy::CompareHash => write_renamed(&path, &content),
+        ConflictPolicy::Skip | ConflictPolicy::Ask => match write_new(&path, &content) {
+            Ok(()) => Ok(DownloadOutcome::Saved(path)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                if policy == ConflictPolicy::Skip {
+                    Ok(DownloadOutcome::Skipped(path))
+                } else {
+                    Ok(DownloadOutcome::Conflict(PendingConflict { path, content }))
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        },
+    }
+}
+
+// 只在文件不存在时创建，批量下载同时写入同名文件时不会互相覆盖
+fn write_new(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(content)
+}
+
+fn write_renamed(path: &Path, content: &[u8]) -> Result<DownloadOutcome, String> {
+    for attempt in 0..=MAX_RENAME_ATTEMPTS {
+        let candidate = if attempt == 0 {
+            path.to_path_buf()
+        } else {
+            numbered_path(path, attempt)
+        };
+        match write_new(&candidate, content) {
+            Ok(()) => return Ok(DownloadOutcome::Saved(candidate)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+    Err(t!("no-free-file-name", path = path.display().to_string()))
+}
+
+// report.pdf -> report (1).pdf
+fn numbered_path(path: &Path, n: u32) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{} ({}).{}", stem, n, extension.to_string_lossy()),
+        None => format!("{} ({})", stem, n),
+    };
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicts_follow_policy() {
+        let dir =
+            std::env::temp_dir().join(format!("walrus_store_download_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("report.txt");
+        fs::write(&path, b"old").unwrap();
+
+        let outcome = save_download(path.clone(), b"new".to_vec(), ConflictPolicy::Skip).unwrap();
+        assert!(matches!(outcome, DownloadOutcome::Skipped(_)));
+        let outcome = save_download(path.clone(), b"new".to_vec(), ConflictPolicy::Ask).unwrap();
+        assert!(matches!(outcome, DownloadOutcome::Conflict(_)));
+        assert_eq!(fs::read(&path).unwrap(), b"old");
+
+        let outcome =
+            save_download(path.clone(), b"old".to_vec(), ConflictPolicy::CompareHash).unwrap();
+        assert!(matches!(outcome, DownloadOutcome::Identical(_)));
+        let outcome = save_download(path.clone(), b"new".to_vec(), ConflictPolicy::Rename).unwrap();
+        let DownloadOutcome::Saved(renamed) = outcome else {
+            panic!("expected a renamed file");
+        };
+        assert_eq!(renamed, dir.join("report (1).txt"));
+        assert_eq!(fs::read(&renamed).unwrap(), b"new");
+
+        let outcome =
+            save_download(path.clone(), b"new".to_vec(), ConflictPolicy::Overwrite).unwrap();
+        assert!(matches!(outcome, DownloadOutcome::Saved(_)));
+        assert_eq!(fs::read(&path).unwrap(), b"new");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn hostile_config_names_stay_inside_download_dir() {
+        let config = r#"[
+            {"id": "a", "name": "../../.bashrc", "uploaded_at": ""},
+            {"id": "b", "name": "/etc/passwd", "uploaded_at": ""},
+            {"id": "c", "name": "C:\\Windows\\System32\\evil.dll", "uploaded_at": ""},
+            {"id": "d", "name": "CON.txt", "uploaded_at": ""},
+            {"id": "e", "name": "a<b>:c?.txt. ", "uploaded_at": ""},
+            {"id": "f", "name": "line\nbreak.txt", "uploaded_at": ""}
+        ]"#;
+        let entries: Vec<crate::data::FileEntry> = serde_json::from_str(config).unwrap();
+        let names: Vec<String> = entries
+            .iter()
+            .map(|entry| sanitize_file_name(&entry.name).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "_bashrc",
+                "passwd",
+                "evil.dll",
+                "_CON.txt",
+                "a_b__c_.txt",
+                "line_break.txt"
+            ]
+        );
+
+        let download_dir = Path::new("downloads");
+        for name in &names {
+            assert_eq!(download_dir.join(name).parent(), Some(download_dir));
+        }
+        for name in ["", "..", "../..", "/", " . "] {
+            assert!(sanitize_file_name(name).is_err(), "{:?}", name);
+        }
+
+        let long_name = format!("{}.pdf", "长".repeat(200));
+        let truncated = sanitize_file_name(&long_name).unwrap();
+        assert!(truncated.len() <= MAX_FILE_NAME_BYTES);
+        assert!(truncated.ends_with(".pdf"));
+    }
+}
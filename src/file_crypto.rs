@@ -0,0 +1,79 @@
+use crate::i18n::t;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+// 加密文件格式：MAGIC + 24 字节随机 nonce + 密文；每个文件使用独立的随机密钥
+const ENCRYPTED_BLOB_MAGIC: &[u8; 8] = b"WSENC\x00\x01\n";
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+pub fn is_encrypted_blob(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_BLOB_MAGIC)
+}
+
+// 用新生成的文件密钥加密内容，返回密文和 Base64 编码的密钥
+pub fn encrypt_blob(plaintext: &[u8]) -> Result<(Vec<u8>, String), String> {
+    let mut key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = XChaCha20Poly1305::new(&key.into())
+        .encrypt(XNonce::from_slice(&nonce), plaintext)
+        .map_err(|e| t!("encrypt-file-failed", error = e.to_string()))?;
+
+    let mut data = Vec::with_capacity(ENCRYPTED_BLOB_MAGIC.len() + NONCE_LEN + ciphertext.len());
+    data.extend_from_slice(ENCRYPTED_BLOB_MAGIC);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+    Ok((data, BASE64.encode(key)))
+}
+
+pub fn decrypt_blob(data: &[u8], file_key: &str) -> Result<Vec<u8>, String> {
+    let rest = data
+        .strip_prefix(ENCRYPTED_BLOB_MAGIC.as_slice())
+        .ok_or_else(|| t!("not-an-encrypted-file"))?;
+    if rest.len() < NONCE_LEN {
+        return Err(t!("encrypted-file-corrupted"));
+    }
+    let key: [u8; KEY_LEN] = BASE64
+        .decode(file_key)
+        .ok()
+        .and_then(|key| key.try_into().ok())
+        .ok_or_else(|| t!("invalid-file-key"))?;
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    XChaCha20Poly1305::new(&key.into())
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| t!("wrong-file-key"))
+}
+
+// 有文件密钥时解密，没有密钥但内容是加密格式时给出明确的错误
+pub fn decrypt_if_needed(data: Vec<u8>, file_key: Option<&str>) -> Result<Vec<u8>, String> {
+    match file_key {
+        Some(file_key) => decrypt_blob(&data, file_key),
+        None if is_encrypted_blob(&data) => Err(t!("missing-file-key")),
+        None => Ok(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_blob_round_trip() {
+        let (data, key) = encrypt_blob(b"secret").unwrap();
+        assert!(is_encrypted_blob(&data));
+        assert_eq!(decrypt_blob(&data, &key).unwrap(), b"secret");
+
+        let (_, other_key) = encrypt_blob(b"other").unwrap();
+        assert!(decrypt_blob(&data, &other_key).is_err());
+        assert!(decrypt_if_needed(data, None).is_err());
+        assert_eq!(
+            decrypt_if_needed(b"plain".to_vec(), None).unwrap(),
+            b"plain"
+        );
+    }
+}
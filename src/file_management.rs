@@ -1,7 +1,19 @@
+use crate::activity::ActivityRecord;
+use crate::config_history::SnapshotRecord;
+use crate::cost::SpendLedger;
 use crate::data::FileEntry;
+use crate::identity::{Contact, Identity};
+use crate::settings::{AppSettings, Preferences};
+use crate::sync::SyncState;
+use crate::theme::NamedTheme;
+use crate::trash::TrashedEntry;
+use crate::workspace::WorkspaceIndex;
 use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::env;
-use std::{fs, path::PathBuf};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 pub fn get_data_dir() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("com", "kilocode", "WalrusStore") {
@@ -20,36 +32,288 @@ pub fn get_data_dir() -> PathBuf {
     }
 }
 
-pub fn get_files_json_path() -> PathBuf {
-    get_data_dir().join("files.json")
+pub fn get_workspaces_dir() -> PathBuf {
+    get_data_dir().join("workspaces")
 }
 
-pub fn load_file_entries() -> Vec<FileEntry> {
-    let path = get_files_json_path();
+// 缩略图按 blob ID 缓存，所有工作区共用
+pub fn get_thumbnail_dir() -> PathBuf {
+    let thumbnail_dir = get_data_dir().join("thumbnails");
+    fs::create_dir_all(&thumbnail_dir).expect("无法创建缩略图目录");
+    thumbnail_dir
+}
+
+// 每个工作区的文件列表、设置和同步状态都保存在独立的目录中
+pub fn get_workspace_dir(workspace: &str) -> PathBuf {
+    let workspace_dir = get_workspaces_dir().join(workspace);
+    fs::create_dir_all(&workspace_dir).expect("无法创建工作区目录");
+    workspace_dir
+}
+
+pub fn get_workspace_index_path() -> PathBuf {
+    get_data_dir().join("workspaces.json")
+}
+
+// 身份密钥和通讯录属于用户本人，所有工作区共用
+pub fn get_identity_path() -> PathBuf {
+    get_data_dir().join("identity.json")
+}
+
+pub fn get_preferences_path() -> PathBuf {
+    get_data_dir().join("preferences.json")
+}
+
+pub fn get_contacts_path() -> PathBuf {
+    get_data_dir().join("contacts.json")
+}
+
+// 自定义主题：数据目录 themes/ 下的每个 JSON 文件是一个主题
+pub fn get_themes_dir() -> PathBuf {
+    get_data_dir().join("themes")
+}
+
+pub fn get_files_json_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("files.json")
+}
+
+pub fn get_config_history_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("config_history.json")
+}
+
+pub fn get_settings_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("settings.json")
+}
+
+pub fn get_sync_state_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("sync_state.json")
+}
+
+pub fn get_trash_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("trash.json")
+}
+
+pub fn get_spend_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("spend.json")
+}
+
+pub fn get_activity_path(workspace: &str) -> PathBuf {
+    get_workspace_dir(workspace).join("activity.json")
+}
+
+// 读取 JSON 数据文件，文件不存在或解析失败时返回默认值
+fn load_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    let file_label = path.file_name().unwrap_or_default().to_string_lossy();
     if path.exists() {
-        match fs::read_to_string(&path) {
+        match fs::read_to_string(path) {
             Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
-                eprintln!("解析 files.json 失败: {}", e);
-                Vec::new()
+                eprintln!("解析 {} 失败: {}", file_label, e);
+                T::default()
             }),
             Err(e) => {
-                eprintln!("读取 files.json 失败: {}", e);
-                Vec::new()
+                eprintln!("读取 {} 失败: {}", file_label, e);
+                T::default()
             }
         }
     } else {
-        Vec::new()
+        T::default()
     }
 }
 
-pub fn save_file_entries(entries: &[FileEntry]) {
-    let path = get_files_json_path();
-    match serde_json::to_string_pretty(entries) {
+fn save_json<T: Serialize + ?Sized>(path: &Path, value: &T) {
+    let file_label = path.file_name().unwrap_or_default().to_string_lossy();
+    match serde_json::to_string_pretty(value) {
         Ok(json) => {
-            fs::write(&path, json).expect("无法写入 files.json");
+            fs::write(path, json).unwrap_or_else(|e| panic!("无法写入 {}: {}", file_label, e));
         }
         Err(e) => {
-            eprintln!("序列化文件列表失败: {}", e);
+            eprintln!("序列化 {} 失败: {}", file_label, e);
+        }
+    }
+}
+
+pub fn load_file_entries(workspace: &str) -> Vec<FileEntry> {
+    load_json(&get_files_json_path(workspace))
+}
+
+pub fn save_file_entries(workspace: &str, entries: &[FileEntry]) {
+    save_json(&get_files_json_path(workspace), entries);
+}
+
+pub fn load_config_history(workspace: &str) -> Vec<SnapshotRecord> {
+    load_json(&get_config_history_path(workspace))
+}
+
+pub fn save_config_history(workspace: &str, history: &[SnapshotRecord]) {
+    save_json(&get_config_history_path(workspace), history);
+}
+
+pub fn load_settings(workspace: &str) -> AppSettings {
+    load_json(&get_settings_path(workspace))
+}
+
+pub fn save_settings(workspace: &str, settings: &AppSettings) {
+    save_json(&get_settings_path(workspace), settings);
+}
+
+// 旧版本把界面偏好保存在各工作区 settings.json 的 display 中
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct LegacySettings {
+    display: Preferences,
+}
+
+// 还没有 preferences.json 时沿用当前工作区旧设置中的偏好
+pub fn load_preferences(workspace: &str) -> Preferences {
+    let path = get_preferences_path();
+    if path.exists() {
+        return load_json(&path);
+    }
+    load_json::<LegacySettings>(&get_settings_path(workspace)).display
+}
+
+pub fn save_preferences(preferences: &Preferences) {
+    save_json(&get_preferences_path(), preferences);
+}
+
+pub fn load_sync_state(workspace: &str) -> SyncState {
+    load_json(&get_sync_state_path(workspace))
+}
+
+pub fn save_sync_state(workspace: &str, state: &SyncState) {
+    save_json(&get_sync_state_path(workspace), state);
+}
+
+pub fn load_trash(workspace: &str) -> Vec<TrashedEntry> {
+    load_json(&get_trash_path(workspace))
+}
+
+pub fn save_trash(workspace: &str, trash: &[TrashedEntry]) {
+    save_json(&get_trash_path(workspace), trash);
+}
+
+pub fn load_spend(workspace: &str) -> SpendLedger {
+    load_json(&get_spend_path(workspace))
+}
+
+pub fn save_spend(workspace: &str, ledger: &SpendLedger) {
+    save_json(&get_spend_path(workspace), ledger);
+}
+
+pub fn load_activity(workspace: &str) -> Vec<ActivityRecord> {
+    load_json(&get_activity_path(workspace))
+}
+
+pub fn save_activity(workspace: &str, activity: &[ActivityRecord]) {
+    save_json(&get_activity_path(workspace), activity);
+}
+
+pub fn load_workspace_index() -> WorkspaceIndex {
+    load_json(&get_workspace_index_path())
+}
+
+pub fn save_workspace_index(index: &WorkspaceIndex) {
+    save_json(&get_workspace_index_path(), index);
+}
+
+pub fn load_identity() -> Option<Identity> {
+    load_json(&get_identity_path())
+}
+
+// identity.json 中的私钥未加密，写入前把文件权限限制为只有当前用户可读写
+pub fn save_identity(identity: &Identity) {
+    let path = get_identity_path();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let restricted = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .mode(0o600)
+            .open(&path)
+            .and_then(|_| fs::set_permissions(&path, fs::Permissions::from_mode(0o600)));
+        if let Err(e) = restricted {
+            eprintln!("无法限制 identity.json 的权限: {}", e);
         }
     }
+    save_json(&path, identity);
+}
+
+pub fn load_contacts() -> Vec<Contact> {
+    load_json(&get_contacts_path())
+}
+
+pub fn save_contacts(contacts: &[Contact]) {
+    save_json(&get_contacts_path(), contacts);
+}
+
+// 读取所有自定义主题，按文件名排序；格式错误的文件跳过并记录日志
+pub fn load_user_themes() -> Vec<NamedTheme> {
+    let Ok(entries) = fs::read_dir(get_themes_dir()) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| {
+            let contents = fs::read_to_string(path)
+                .map_err(|e| eprintln!("读取主题 {} 失败: {}", path.display(), e))
+                .ok()?;
+            serde_json::from_str(&contents)
+                .map_err(|e| eprintln!("解析主题 {} 失败: {}", path.display(), e))
+                .ok()
+        })
+        .collect()
+}
+
+// 展开拖入的路径：文件直接返回，文件夹递归收集其中的文件（不跟随符号链接），按路径排序
+pub fn collect_upload_files(path: &Path) -> Vec<PathBuf> {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return Vec::new();
+    };
+    if metadata.is_file() {
+        return vec![path.to_path_buf()];
+    }
+    if !metadata.is_dir() {
+        return Vec::new();
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .flat_map(|entry| collect_upload_files(&entry.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropped_folders_are_expanded() {
+        let dir = env::temp_dir().join(format!("walrus_store_drop_{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("b.txt"), b"b").unwrap();
+        fs::write(dir.join("nested").join("a.txt"), b"a").unwrap();
+
+        assert_eq!(
+            collect_upload_files(&dir),
+            [dir.join("b.txt"), dir.join("nested").join("a.txt")]
+        );
+        assert_eq!(
+            collect_upload_files(&dir.join("b.txt")),
+            [dir.join("b.txt")]
+        );
+        assert!(collect_upload_files(&dir.join("missing")).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,189 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+use unic_langid::LanguageIdentifier;
+
+// 消息目录使用 Fluent 格式，随程序一起编译
+const ZH_CN_CATALOG: &str = include_str!("../assets/locales/zh-CN.ftl");
+const EN_CATALOG: &str = include_str!("../assets/locales/en.ftl");
+
+// t!("key") 或 t!("key", name = value, ...)，参数名与消息目录中的 $name 对应
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($key, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+// 界面实际使用的语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    ZhCn,
+    En,
+}
+
+impl Locale {
+    const ALL: [Locale; 2] = [Locale::ZhCn, Locale::En];
+
+    fn tag(self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::En => "en",
+        }
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Locale::ZhCn => ZH_CN_CATALOG,
+            Locale::En => EN_CATALOG,
+        }
+    }
+}
+
+// 设置中的语言选项，默认跟随系统
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[serde(rename = "en")]
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::Auto, Language::ZhCn, Language::En];
+
+    pub fn locale(self) -> Locale {
+        match self {
+            Language::Auto => detect_locale(),
+            Language::ZhCn => Locale::ZhCn,
+            Language::En => Locale::En,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 语言名称使用各自的语言书写，方便在看不懂当前界面时也能切换
+        match self {
+            Language::Auto => write!(f, "{}", t!("language-auto")),
+            Language::ZhCn => write!(f, "简体中文"),
+            Language::En => write!(f, "English"),
+        }
+    }
+}
+
+// 系统语言为中文时使用中文，其他情况使用英文
+pub fn detect_locale() -> Locale {
+    locale_for_tag(&sys_locale::get_locale().unwrap_or_default())
+}
+
+fn locale_for_tag(tag: &str) -> Locale {
+    if tag.to_ascii_lowercase().starts_with("zh") {
+        Locale::ZhCn
+    } else {
+        Locale::En
+    }
+}
+
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(language: Language) {
+    let locale = language.locale();
+    let index = Locale::ALL.iter().position(|l| *l == locale).unwrap_or(0);
+    CURRENT_LOCALE.store(index as u8, Ordering::Relaxed);
+}
+
+pub fn current_locale() -> Locale {
+    Locale::ALL[CURRENT_LOCALE.load(Ordering::Relaxed) as usize % Locale::ALL.len()]
+}
+
+fn bundles() -> &'static [FluentBundle<FluentResource>] {
+    static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+    BUNDLES.get_or_init(|| {
+        Locale::ALL
+            .iter()
+            .map(|locale| {
+                let langid: LanguageIdentifier = locale.tag().parse().expect("语言标识无效");
+                let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+                // 界面文本不需要 Unicode 方向隔离符
+                bundle.set_use_isolating(false);
+                let resource = FluentResource::try_new(locale.catalog().to_string())
+                    .unwrap_or_else(|(_, errors)| {
+                        panic!("{} 消息目录解析失败: {:?}", locale.tag(), errors)
+                    });
+                bundle.add_resource(resource).unwrap_or_else(|errors| {
+                    panic!("{} 消息目录有重复的键: {:?}", locale.tag(), errors)
+                });
+                bundle
+            })
+            .collect()
+    })
+}
+
+fn format_in(locale: Locale, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let index = Locale::ALL.iter().position(|l| *l == locale)?;
+    let bundle = &bundles()[index];
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        eprintln!("格式化消息 {} 出错: {:?}", key, errors);
+    }
+    Some(text.into_owned())
+}
+
+// 按当前语言格式化消息；缺少翻译时回退到中文，再不行就显示键名
+pub fn translate(key: &str, args: Option<&FluentArgs>) -> String {
+    format_in(current_locale(), key, args)
+        .or_else(|| format_in(Locale::ZhCn, key, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    // 消息定义以 "键 =" 开头，续行和注释都不会以小写字母开头
+    fn message_ids(catalog: &str) -> BTreeSet<&str> {
+        catalog
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(key, _)| key))
+            .collect()
+    }
+
+    #[test]
+    fn catalogs_have_the_same_keys() {
+        let zh = message_ids(ZH_CN_CATALOG);
+        let en = message_ids(EN_CATALOG);
+        assert!(!zh.is_empty());
+        assert_eq!(
+            zh.symmetric_difference(&en).collect::<Vec<_>>(),
+            Vec::<&&str>::new()
+        );
+
+        let mut args = FluentArgs::new();
+        args.set("count", 2);
+        assert_eq!(
+            format_in(Locale::En, "batch-download-started", Some(&args)).unwrap(),
+            "Downloading 2 files..."
+        );
+        assert!(format_in(Locale::En, "no-such-key", None).is_none());
+
+        assert_eq!(locale_for_tag("zh-Hans-CN"), Locale::ZhCn);
+        assert_eq!(locale_for_tag("en-US"), Locale::En);
+        assert_eq!(locale_for_tag(""), Locale::En);
+    }
+}
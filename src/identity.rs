@@ -0,0 +1,241 @@
+use crate::file_management::{load_contacts, load_identity, save_identity};
+use crate::i18n::t;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use chrono::Utc;
+use hkdf::Hkdf;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+// 公钥的文本格式：前缀 + Base64，方便用户复制和识别
+pub const PUBLIC_KEY_PREFIX: &str = "ws25519:";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+const WRAP_KEY_INFO: &[u8] = b"walrus_store recipient key wrap v1";
+
+// 本机的身份密钥，保存在数据目录的 identity.json 中，所有工作区共用
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Identity {
+    pub secret: String, // Base64 编码的 X25519 私钥
+    pub public_key: String,
+    pub created_at: String,
+}
+
+// 不在日志和调试输出中泄露私钥
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Identity")
+            .field("public_key", &self.public_key)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
+impl Identity {
+    pub fn generate() -> Self {
+        let secret = StaticSecret::random_from_rng(OsRng);
+        Self {
+            secret: BASE64.encode(secret.to_bytes()),
+            public_key: encode_public_key(&PublicKey::from(&secret)),
+            created_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+
+    fn secret_key(&self) -> Result<StaticSecret, String> {
+        let bytes: [u8; KEY_LEN] = BASE64
+            .decode(&self.secret)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| t!("identity-corrupted"))?;
+        Ok(StaticSecret::from(bytes))
+    }
+}
+
+// 通讯录中的联系人，分享时可以把文件密钥加密给对方的公钥
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: String,
+    pub public_key: String,
+}
+
+pub fn encode_public_key(public_key: &PublicKey) -> String {
+    format!(
+        "{}{}",
+        PUBLIC_KEY_PREFIX,
+        BASE64.encode(public_key.as_bytes())
+    )
+}
+
+pub fn parse_public_key(text: &str) -> Result<PublicKey, String> {
+    let bytes: [u8; KEY_LEN] = text
+        .trim()
+        .strip_prefix(PUBLIC_KEY_PREFIX)
+        .and_then(|encoded| BASE64.decode(encoded).ok())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| t!("invalid-public-key", prefix = PUBLIC_KEY_PREFIX))?;
+    Ok(PublicKey::from(bytes))
+}
+
+// 公钥指纹，便于双方口头核对
+pub fn fingerprint(public_key: &str) -> String {
+    let digest = Sha256::digest(public_key.trim().as_bytes());
+    digest[..8]
+        .chunks(2)
+        .map(|pair| format!("{:02X}{:02X}", pair[0], pair[1]))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// 第一次使用时生成身份密钥
+pub fn load_or_create_identity() -> Identity {
+    if let Some(identity) = load_identity() {
+        return identity;
+    }
+    let identity = Identity::generate();
+    save_identity(&identity);
+    identity
+}
+
+pub fn validate_contact(name: &str, public_key: &str) -> Result<Contact, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(t!("empty-contact-name"));
+    }
+    let public_key = encode_public_key(&parse_public_key(public_key)?);
+    if load_contacts().iter().any(|c| c.public_key == public_key) {
+        return Err(t!("duplicate-contact"));
+    }
+    Ok(Contact {
+        name: name.to_string(),
+        public_key,
+    })
+}
+
+// 按接收人加密的数据：内容密钥分别为每个接收人包装一次
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SealedPayload {
+    pub recipients: Vec<RecipientStanza>,
+    pub nonce: String,      // Base64
+    pub ciphertext: String, // Base64
+}
+
+// 类似 age 的 X25519 接收人条目：临时密钥与接收人公钥协商出包装密钥
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipientStanza {
+    pub recipient: String, // 接收人公钥
+    pub ephemeral: String, // Base64 编码的临时公钥
+    pub nonce: String,
+    pub wrapped_key: String,
+}
+
+fn wrap_key(shared_secret: &[u8], ephemeral: &PublicKey, recipient: &PublicKey) -> [u8; KEY_LEN] {
+    let mut salt = Vec::with_capacity(KEY_LEN * 2);
+    salt.extend_from_slice(ephemeral.as_bytes());
+    salt.extend_from_slice(recipient.as_bytes());
+    let mut key = [0u8; KEY_LEN];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(WRAP_KEY_INFO, &mut key)
+        .expect("32 字节在 HKDF-SHA256 的输出长度范围内");
+    key
+}
+
+fn encrypt_with(key: &[u8; KEY_LEN], plaintext: &[u8]) -> Result<(String, String), String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = XChaCha20Poly1305::new(key.into())
+        .encrypt(XNonce::from_slice(&nonce), plaintext)
+        .map_err(|e| t!("encrypt-failed", error = e.to_string()))?;
+    Ok((BASE64.encode(nonce), BASE64.encode(ciphertext)))
+}
+
+fn decrypt_with(key: &[u8; KEY_LEN], nonce: &str, ciphertext: &str) -> Option<Vec<u8>> {
+    let nonce = BASE64.decode(nonce).ok().filter(|n| n.len() == NONCE_LEN)?;
+    let ciphertext = BASE64.decode(ciphertext).ok()?;
+    XChaCha20Poly1305::new(key.into())
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .ok()
+}
+
+pub fn seal(plaintext: &[u8], recipients: &[String]) -> Result<SealedPayload, String> {
+    if recipients.is_empty() {
+        return Err(t!("no-recipients"));
+    }
+    let mut content_key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut content_key);
+    let stanzas = recipients
+        .iter()
+        .map(|recipient| {
+            let recipient_key = parse_public_key(recipient)?;
+            let ephemeral_secret = StaticSecret::random_from_rng(OsRng);
+            let ephemeral = PublicKey::from(&ephemeral_secret);
+            let shared = ephemeral_secret.diffie_hellman(&recipient_key);
+            let key = wrap_key(shared.as_bytes(), &ephemeral, &recipient_key);
+            let (nonce, wrapped_key) = encrypt_with(&key, &content_key)?;
+            Ok(RecipientStanza {
+                recipient: encode_public_key(&recipient_key),
+                ephemeral: BASE64.encode(ephemeral.as_bytes()),
+                nonce,
+                wrapped_key,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let (nonce, ciphertext) = encrypt_with(&content_key, plaintext)?;
+    Ok(SealedPayload {
+        recipients: stanzas,
+        nonce,
+        ciphertext,
+    })
+}
+
+pub fn open(payload: &SealedPayload, identity: &Identity) -> Result<Vec<u8>, String> {
+    let secret = identity.secret_key()?;
+    let own_key = PublicKey::from(&secret);
+    let stanza = payload
+        .recipients
+        .iter()
+        .find(|stanza| parse_public_key(&stanza.recipient).is_ok_and(|key| key == own_key))
+        .ok_or_else(|| t!("not-a-recipient"))?;
+    let ephemeral: [u8; KEY_LEN] = BASE64
+        .decode(&stanza.ephemeral)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| t!("recipient-corrupted"))?;
+    let ephemeral = PublicKey::from(ephemeral);
+    let shared = secret.diffie_hellman(&ephemeral);
+    let key = wrap_key(shared.as_bytes(), &ephemeral, &own_key);
+    let content_key: [u8; KEY_LEN] = decrypt_with(&key, &stanza.nonce, &stanza.wrapped_key)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| t!("unwrap-key-failed"))?;
+    decrypt_with(&content_key, &payload.nonce, &payload.ciphertext)
+        .ok_or_else(|| t!("decrypt-failed"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_payloads_open_only_for_recipients() {
+        let alice = Identity::generate();
+        let bob = Identity::generate();
+        let eve = Identity::generate();
+
+        let sealed = seal(
+            b"bundle",
+            &[alice.public_key.clone(), bob.public_key.clone()],
+        )
+        .unwrap();
+        assert_eq!(open(&sealed, &alice).unwrap(), b"bundle");
+        assert_eq!(open(&sealed, &bob).unwrap(), b"bundle");
+        assert!(open(&sealed, &eve).is_err());
+
+        assert!(parse_public_key("ws25519:abc").is_err());
+        assert!(seal(b"bundle", &[]).is_err());
+        assert_eq!(fingerprint(&alice.public_key).len(), 19);
+    }
+}
@@ -2,22 +2,65 @@
 
 use iced::window;
 use iced::window::icon;
-use iced::{Application, Command, Element, Font, Settings, Theme};
-use std::collections::HashSet;
+use iced::{Application, Command, Element, Font, Settings, Subscription, Theme};
+use std::collections::{HashMap, HashSet, VecDeque};
 
+mod activity;
 mod app_logic;
+mod blob_delete;
+mod blob_envelope;
+mod bundle;
+mod commands;
+mod config_crypto;
+mod config_history;
+mod config_merge;
+mod confirm;
+mod cost;
 mod data;
+mod download;
+mod file_crypto;
 mod file_management;
+mod i18n;
+mod identity;
+mod preview;
+mod selection;
+mod settings;
+mod share;
+mod staging;
+mod sync;
+mod theme;
+mod thumbnail;
+mod trash;
 
 mod ui;
+mod wallet;
 mod walrus_api;
+mod workspace;
 
+use crate::activity::{ActivityRecord, KindFilter, ResultFilter, Timed};
+use crate::bundle::ShareBundle;
+use crate::commands::{PaletteCommand, shortcut_message};
+use crate::config_history::{ConfigSnapshot, SnapshotAction, SnapshotRecord};
+use crate::config_merge::{ImportMode, PendingImport};
+use crate::confirm::ConfirmAction;
+use crate::cost::{PriceInfo, PriceStatus, SpendLedger};
 use crate::data::FileEntry;
-use crate::file_management::load_file_entries;
+use crate::download::{ConflictPolicy, DownloadOutcome, PendingConflict};
+use crate::i18n::{Language, set_language, t};
+use crate::identity::{Contact, Identity, load_or_create_identity};
+use crate::preview::{PreviewData, PreviewPane};
+use crate::settings::{AppSettings, NetworkProfile, Preferences};
+use crate::share::ShareDialog;
+use crate::staging::{StagedUpload, UploadJob};
+use crate::sync::{SyncConflict, SyncOutcome, SyncState};
+use crate::theme::NamedTheme;
+use crate::thumbnail::ThumbnailStatus;
+use crate::trash::TrashedEntry;
 // use crate::mock_api::MockApi; // 注释掉或删除
 use crate::ui::view_application;
-use app_logic::handle_message; // Add this line
-use file_management::get_data_dir;
+use crate::workspace::{TransferMode, load_active_workspace};
+use app_logic::{apply_theme, handle_message, load_workspace, request_thumbnails}; // Add this line
+use file_management::{get_data_dir, load_contacts, load_preferences};
 use std::path::PathBuf;
 
 // 定义应用程序的状态
@@ -25,11 +68,68 @@ use std::path::PathBuf;
 pub struct WalrusStore {
     pub files: Vec<FileEntry>,
     pub upload_progress: f32,
-    pub upload_file_path: String,
+    pub upload_staging: Vec<StagedUpload>, // 已选择、等待确认上传的文件
     pub download_id_input: String,
     pub status_message: String,
     pub search_input: String, // 用于文件搜索的输入
     pub selected_files: std::collections::HashSet<String>, // 新增，用于存储选中的文件ID
+    pub import_mode: ImportMode, // 新导入预览默认使用的合并模式
+    pub pending_import: Option<PendingImport>, // 等待确认的导入（预览中）
+    pub import_undo: Option<Vec<FileEntry>>, // 最近一次导入前的文件列表，用于撤销
+    pub config_passphrase: String, // 远程配置加密/解密使用的密码
+    pub pending_encrypted_config: Option<String>, // 等待输入密码解密的远程配置
+    pub config_history: Vec<SnapshotRecord>, // 已发布的配置快照，按发布顺序排列
+    pub show_config_history: bool, // 是否显示配置历史浏览器
+    pub snapshot_epochs_input: String, // 配置快照存储周期输入框内容
+    pub snapshot_diff: Option<(String, ConfigSnapshot)>, // 正在与当前列表对比的快照
+    pub settings: AppSettings, // 持久化的用户设置
+    pub preferences: Preferences, // 所有工作区共用的界面偏好
+    pub sync_state: SyncState, // 本设备的同步状态
+    pub show_sync_panel: bool, // 是否显示同步设置面板
+    pub sync_in_progress: bool, // 是否正在同步
+    pub sync_interval_input: String, // 同步间隔输入框内容（分钟）
+    pub last_sync_conflicts: Vec<SyncConflict>, // 最近一次同步的冲突
+    pub workspace: String,    // 当前工作区名称
+    pub workspaces: Vec<String>, // 所有工作区名称
+    pub show_workspace_panel: bool, // 是否显示工作区管理面板
+    pub new_workspace_name: String, // 新建工作区名称输入框内容
+    pub network_input: NetworkProfile, // 正在编辑的网络配置
+    pub transfer_target: Option<String>, // 复制/移动选中记录的目标工作区
+    pub add_blob_name: String, // 添加已有 blob 时的文件名输入框内容
+    pub add_blob_tags: String, // 添加已有 blob 时的标签输入框内容（逗号分隔）
+    pub download_conflicts: Vec<PendingConflict>, // 等待用户决定的下载冲突
+    pub remember_conflict_choice: bool, // 把本次冲突的处理方式设为默认
+    pub preview: Option<PreviewPane>, // 文件预览面板
+    pub upload_queue: VecDeque<UploadJob>, // 等待上传的文件（拖放或全部上传）
+    pub upload_in_progress: bool, // 是否正在上传
+    pub probes_in_progress: usize, // 正在探测的已有 blob 数
+    pub drop_hover: bool,     // 是否有文件拖到窗口上方
+    pub share_dialog: Option<ShareDialog>, // 正在分享的文件
+    pub open_bundle: Option<(String, ShareBundle)>, // 正在查看的分享包（清单 ID, 内容）
+    pub thumbnails: HashMap<String, ThumbnailStatus>, // 网格视图的缩略图，按 blob ID 索引
+    pub identity: Option<Identity>, // 本机的身份密钥，用于打开加密分享包
+    pub contacts: Vec<Contact>, // 通讯录
+    pub show_identity_panel: bool, // 是否显示身份密钥与通讯录面板
+    pub new_contact_name: String, // 新联系人名称输入框内容
+    pub new_contact_key: String, // 新联系人公钥输入框内容
+    pub share_recipients: HashSet<String>, // 分享包的接收人公钥
+    pub themes: Vec<NamedTheme>, // 可选的主题（内置和自定义）
+    pub ui_theme: Theme,      // 由当前主题生成的 iced 主题
+    pub command_palette: Option<String>, // 打开的命令面板及其搜索内容
+    pub selection_anchor: Option<String>, // 最近一次单独点击的文件，Shift 范围选择的起点
+    pub keyboard_modifiers: iced::keyboard::Modifiers, // 当前按下的修饰键
+    pub pending_confirmation: Option<ConfirmAction>, // 等待用户确认的破坏性操作
+    pub trash: Vec<TrashedEntry>, // 回收站中的记录
+    pub show_trash_panel: bool, // 是否显示回收站
+    pub trash_retention_input: String, // 回收站保留天数输入框内容
+    pub sui_address_input: String, // Sui 地址输入框内容，有效时才保存到设置
+    pub keystore_addresses: Vec<String>, // 从本地密钥库读取的地址
+    pub price_status: PriceStatus, // 用于估算上传费用的网络价格
+    pub spend: SpendLedger,   // 本工作区累计的存储费用
+    pub activity: Vec<ActivityRecord>, // 本工作区的活动日志
+    pub show_activity_panel: bool, // 是否显示活动日志
+    pub activity_kind_filter: KindFilter, // 活动日志按类型筛选
+    pub activity_result_filter: ResultFilter, // 活动日志按结果筛选
 }
 
 // 定义应用程序的消息
@@ -37,18 +137,62 @@ pub struct WalrusStore {
 pub enum Message {
     FilesLoaded(Vec<FileEntry>),
     TriggerFileSelection,
-    FileSelected(Option<PathBuf>),
-    UploadButtonPressed,
+    FilesSelected(Option<Vec<PathBuf>>), // 文件选择对话框返回（可多选）
+    StagedNameChanged(usize, String),    // 修改暂存文件的目标文件名
+    StagedTagsChanged(usize, String),    // 修改暂存文件的标签
+    StagedEpochsChanged(usize, String),  // 修改暂存文件的存储周期
+    RemoveStagedFile(usize),             // 从暂存列表中移除文件
+    ClearStaging,                        // 清空暂存列表
+    UploadButtonPressed,                 // 全部上传
+    FilesHovered,                        // 文件被拖到窗口上方
+    FilesHoveredLeft,                    // 拖动的文件离开窗口
+    FileDropped(PathBuf),                // 文件或文件夹被拖放到窗口中，每个路径一条消息
     DownloadButtonPressed(String),
     TriggerDownloadSelection(String),
     DownloadLocationSelected(Option<PathBuf>, String),
     CopyIdToClipboard(String),
+    ShareButtonPressed(String),    // 打开文件的分享对话框
+    ShareIncludeNameToggled(bool), // 分享链接中是否附带文件名
+    CopyShareLink,                 // 复制分享链接
+    CloseShareDialog,              // 关闭分享对话框
     DeleteButtonPressed(String),
     FileSelectedForBatch(String, bool), // 用于批量操作中选择/取消选择文件 (文件ID, 是否选中)
     BatchDeleteButtonPressed,           // 批量删除按钮
     BatchDownloadButtonPressed,         // 批量下载按钮
     TriggerBatchDownloadSelection,      // 触发批量下载的路径选择
     BatchDownloadLocationSelected(Option<PathBuf>), // 批量下载路径选择完成
+    ShareSelectionPressed,              // 把选中的记录发布为分享包
+    BundlePublished(Timed<Result<String, String>>), // 分享包发布完成，包含清单 ID
+    OpenBundlePressed,                  // 打开输入框中 ID 对应的分享包
+    BundleLoaded(String, Result<ShareBundle, String>), // 分享包读取完成
+    ImportBundlePressed,                // 把分享包中的记录导入文件列表
+    TriggerBundleDownloadSelection,     // 选择下载分享包全部文件的目录
+    BundleDownloadLocationSelected(Option<PathBuf>), // 分享包下载目录选择完成
+    CloseBundle,                        // 关闭分享包
+    ToggleIdentityPanel,                // 显示/隐藏身份密钥与通讯录面板
+    CopyPublicKey,                      // 复制本机公钥
+    NewContactNameChanged(String),      // 新联系人名称输入框变化
+    NewContactKeyChanged(String),       // 新联系人公钥输入框变化
+    AddContactPressed,                  // 添加联系人
+    RemoveContact(String),              // 按公钥删除联系人
+    ShareRecipientToggled(String, bool), // 选择/取消分享包的接收人
+    EncryptUploadsToggled(bool),        // 上传时是否加密文件内容
+    DeletableUploadsToggled(bool),      // 上传时是否以可删除方式存储
+    DeleteFromWalrusPressed(String),    // 从 Walrus 删除可删除的 blob
+    WalrusCliChanged(String),           // walrus 命令行客户端路径输入框变化
+    WalrusConfigChanged(String),        // walrus 客户端配置文件路径输入框变化
+    SuiAddressChanged(String),          // Sui 地址输入框变化或从密钥库地址中选择
+    KeystorePathChanged(String),        // Sui 密钥库路径输入框变化
+    LoadKeystorePressed,                // 读取密钥库中的地址
+    RefreshPricePressed,                // 重新查询网络价格
+    PriceInfoLoaded(Result<PriceInfo, String>), // 网络价格查询完成
+    ToggleActivityPanel,                // 显示/隐藏活动日志
+    ActivityKindFilterChanged(KindFilter), // 活动日志类型筛选变化
+    ActivityResultFilterChanged(ResultFilter), // 活动日志结果筛选变化
+    TriggerExportActivity,              // 选择活动日志 CSV 的保存位置
+    ExportActivitySelected(Option<PathBuf>), // 导出当前筛选的活动日志
+    LanguageSelected(Language),         // 切换界面语言
+    ThemeSelected(String),              // 切换主题
     DownloadInputChanged(String),
     DownloadFromInputButtonPressed,
     TriggerDownloadSelectionFromInput(String), // 用于从输入框下载时选择路径
@@ -58,15 +202,79 @@ pub enum Message {
     TriggerImportConfig,                       // 触发导入配置文件
     ImportConfigSelected(Option<PathBuf>),     // 导入配置文件路径选择完成
     UploadConfigButtonPressed,                 // 新增：上传当前配置
-    UploadConfigSuccess(String),               // 新增：配置上传成功，包含blob ID
+    UploadConfigComplete(Timed<Result<SnapshotRecord, String>>), // 配置上传完成
     LoadConfigFromIdButtonPressed,             // 新增：加载远程配置按钮被按下
     ConfigLoaded(Result<String, String>),      // 新增：远程配置加载完成，包含配置内容
+    ImportModeChanged(ImportMode),             // 导入预览中切换合并模式
+    ConfirmImport,                             // 确认应用导入预览
+    CancelImport,                              // 取消导入
+    UndoLastImport,                            // 撤销最近一次导入
+    ConfigPassphraseChanged(String),           // 配置密码输入框变化
+    DecryptConfigButtonPressed,                // 使用输入的密码解密远程配置
+    ConfigDecrypted(Result<String, String>),   // 远程配置解密完成
+    CancelDecryptConfig,                       // 取消解密远程配置
+    ToggleConfigHistory,                       // 显示/隐藏配置历史
+    SnapshotEpochsChanged(String),             // 配置快照存储周期输入框变化
+    FetchSnapshot(String, SnapshotAction),     // 读取历史快照用于对比或恢复
+    SnapshotFetched(String, SnapshotAction, Result<ConfigSnapshot, String>), // 历史快照读取完成
+    CloseSnapshotDiff,                         // 关闭快照对比
+    ToggleSyncPanel,                           // 显示/隐藏同步设置面板
+    SyncEnabledToggled(bool),                  // 开启/关闭自动同步
+    SyncPointerPathChanged(String),            // 同步指针文件路径输入框变化
+    TriggerSyncPointerSelection,               // 选择同步指针文件
+    SyncPointerSelected(Option<PathBuf>),      // 同步指针文件选择完成
+    SyncIntervalChanged(String),               // 同步间隔输入框变化
+    SyncNowPressed,                            // 立即同步
+    SyncTick,                                  // 定时同步触发
+    SyncCompleted(Timed<Result<SyncOutcome, String>>), // 同步完成
+    WorkspaceSelected(String),                 // 切换工作区
+    ToggleWorkspacePanel,                      // 显示/隐藏工作区管理面板
+    NewWorkspaceNameChanged(String),           // 新建工作区名称输入框变化
+    CreateWorkspacePressed,                    // 新建工作区
+    DeleteWorkspacePressed(String),            // 删除工作区
+    AggregatorUrlChanged(String),              // 聚合器地址输入框变化
+    PublisherUrlChanged(String),               // 发布器地址输入框变化
+    SaveNetworkProfilePressed,                 // 保存当前工作区的网络配置
+    TransferTargetSelected(String),            // 选择复制/移动的目标工作区
+    TransferSelectedEntries(TransferMode),     // 复制/移动选中的记录到目标工作区
+    AddBlobNameChanged(String),                // 添加已有 blob 的文件名输入框变化
+    AddBlobTagsChanged(String),                // 添加已有 blob 的标签输入框变化
+    AddExistingBlobPressed,                    // 把输入的 blob ID 添加到文件列表
+    ExistingBlobProbed(Timed<Result<FileEntry, String>>), // 已有 blob 探测完成
+    ToggleGridView,                            // 在列表和缩略图网格之间切换
+    ThumbnailLoaded(String, Result<PathBuf, String>), // 后台缩略图生成完成
+    PreviewButtonPressed(String),              // 在预览面板中打开文件
+    PreviewLoaded(String, Result<PreviewData, String>), // 预览内容读取完成
+    PreviewEditorAction(iced::widget::text_editor::Action), // 文本预览中的光标移动和选择
+    ClosePreview,                              // 关闭预览面板
+    ConflictPolicyChanged(ConflictPolicy),     // 切换下载冲突的默认处理方式
+    ResolveDownloadConflict(ConflictPolicy),   // 处理第一个等待决定的下载冲突
+    RememberConflictChoiceToggled(bool),       // 切换是否记住冲突的处理方式
+    WrapMetadataToggled(bool),                 // 上传时是否附带文件名等元数据
     UploadProgress(f32),
-    UploadComplete(Result<FileEntry, String>),
-    DownloadComplete(Result<String, String>),
-    DeleteComplete(Result<String, String>),
+    UploadComplete(Timed<Result<FileEntry, String>>),
+    DownloadComplete(Timed<Result<DownloadOutcome, String>>),
+    DeleteComplete(String, Timed<Result<String, String>>), // 发起删除的工作区, 结果
     StatusMessage(String),
-    SearchInputChanged(String), // 用于文件搜索输入框变化的事件
+    SearchInputChanged(String),    // 用于文件搜索输入框变化的事件
+    FocusSearch,                   // 把焦点移到搜索框
+    SelectAllVisibleToggled(bool), // 选中/取消当前搜索结果中的全部文件
+    InvertSelection,               // 反选当前搜索结果中的文件
+    ModifiersChanged(iced::keyboard::Modifiers), // 修饰键状态变化，用于 Shift 范围选择
+    ToggleCommandPalette,          // 打开/关闭命令面板
+    CommandPaletteInputChanged(String), // 命令面板搜索框变化
+    CommandPaletteSubmit,          // 执行命令面板中排在第一的命令
+    RunCommand(PaletteCommand),    // 执行命令面板中点击的命令
+    Shortcut(PaletteCommand),      // 快捷键触发的命令，打开对话框或面板时忽略
+    CloseCommandPalette,           // 关闭命令面板
+    ConfirmPendingAction,          // 确认对话框中点击确认
+    CancelPendingAction,           // 确认对话框中点击取消
+    ToggleTrashPanel,              // 显示/隐藏回收站
+    RestoreFromTrash(String),      // 把回收站中的记录放回文件列表
+    PurgeFromTrashPressed(String), // 从回收站永久删除一条记录
+    EmptyTrashPressed,             // 清空回收站
+    TrashRetentionChanged(String), // 回收站保留天数输入框变化
+    TrashRetentionSubmitted,       // 应用输入的回收站保留天数
     NoOp,
 }
 
@@ -78,39 +286,59 @@ impl Application for WalrusStore {
     type Flags = ();
 
     fn new(_flags: Self::Flags) -> (WalrusStore, Command<Message>) {
-        (
-            WalrusStore {
-                files: load_file_entries(),
-                search_input: String::new(),    // 初始化搜索输入为空
-                selected_files: HashSet::new(), // 初始化选中的文件ID为空
-                ..Default::default()
-            },
-            Command::none(),
-        )
+        let mut app = WalrusStore {
+            search_input: String::new(),    // 初始化搜索输入为空
+            selected_files: HashSet::new(), // 初始化选中的文件ID为空
+            identity: Some(load_or_create_identity()),
+            contacts: load_contacts(),
+            ..Default::default()
+        };
+        let workspace = load_active_workspace();
+        app.preferences = load_preferences(&workspace);
+        set_language(app.preferences.language);
+        apply_theme(&mut app);
+        load_workspace(&mut app, workspace);
+        (app, Command::none())
     }
 
     fn title(&self) -> String {
-        String::from("walrus云盘")
+        t!("window-title", workspace = self.workspace.as_str())
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
-        handle_message(self, message)
+        let command = handle_message(self, message);
+        Command::batch([command, request_thumbnails(self)])
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let sync = &self.settings.sync;
+        let sync_subscription = if sync.enabled && !sync.pointer_path.is_empty() {
+            let interval = std::time::Duration::from_secs(sync.interval_minutes.max(1) * 60);
+            iced::time::every(interval).map(|_| Message::SyncTick)
+        } else {
+            Subscription::none()
+        };
+        // 监听窗口的文件拖放事件
+        let drop_subscription = iced::event::listen_with(|event, _status| match event {
+            iced::Event::Window(_, window::Event::FileHovered(_)) => Some(Message::FilesHovered),
+            iced::Event::Window(_, window::Event::FilesHoveredLeft) => {
+                Some(Message::FilesHoveredLeft)
+            }
+            iced::Event::Window(_, window::Event::FileDropped(path)) => {
+                Some(Message::FileDropped(path))
+            }
+            _ => None,
+        });
+        let shortcut_subscription = iced::event::listen_with(shortcut_message);
+        Subscription::batch([sync_subscription, drop_subscription, shortcut_subscription])
     }
 
     fn view(&self) -> Element<'_, Message> {
-        view_application(
-            &self.files,
-            &self.upload_file_path,
-            self.upload_progress,
-            &self.download_id_input,
-            &self.status_message,
-            &self.search_input,   // 添加 search_input 参数
-            &self.selected_files, // 新增，传递 selected_files
-        )
+        view_application(self)
     }
 
     fn theme(&self) -> Theme {
-        Theme::Dark // 返回 Dark 主题
+        self.ui_theme.clone()
     }
 }
 
@@ -125,7 +353,7 @@ pub fn main() -> iced::Result {
     #[cfg(target_os = "macos")]
     let font = Font::with_name("PingFang SC");
     #[cfg(target_os = "linux")]
-    let font =  Font::with_name("Roboto");
+    let font = Font::with_name("Roboto");
     let config = Settings {
         default_font: font,
         window: window::Settings {
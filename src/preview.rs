@@ -0,0 +1,404 @@
+use crate::blob_envelope;
+use crate::i18n::t;
+use iced::widget::{image, svg, text_editor};
+use std::fmt::Write;
+use std::path::Path;
+
+// 预览时最多读取的字节数，超出部分不下载
+pub const PREVIEW_SIZE_LIMIT: usize = 8 * 1024 * 1024;
+// 十六进制视图最多显示的字节数
+const HEX_VIEW_LIMIT: usize = 16 * 1024;
+const HEX_BYTES_PER_LINE: usize = 16;
+const PDF_MAGIC: &[u8] = b"%PDF-";
+
+// 下载到内存中的预览数据
+#[derive(Debug, Clone)]
+pub struct PreviewData {
+    pub name: String,
+    pub content: Vec<u8>,
+    pub truncated: bool, // 内容超过 PREVIEW_SIZE_LIMIT，只读取了开头部分
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreviewKind {
+    Image,
+    Svg,
+    Text { extension: String }, // 扩展名用于选择语法高亮
+    Pdf,
+    Binary,
+}
+
+// 拆开元数据信封（如果有），文件名优先使用调用方给出的名称
+pub fn prepare_preview(
+    name: Option<String>,
+    data: Vec<u8>,
+    truncated: bool,
+) -> Result<PreviewData, String> {
+    // 被截断的内容无法校验信封中的哈希，只拆开头部取得文件名
+    let (metadata, content) = if truncated {
+        blob_envelope::unwrap_prefix(data)?
+    } else {
+        blob_envelope::unwrap(data)?
+    };
+    let name = name
+        .or_else(|| metadata.map(|metadata| metadata.name))
+        .unwrap_or_default();
+    Ok(PreviewData {
+        name,
+        content,
+        truncated,
+    })
+}
+
+pub fn classify(data: &PreviewData) -> PreviewKind {
+    let extension = Path::new(&data.name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !data.truncated
+        && let Some(kind) = infer::get(&data.content)
+        && kind.matcher_type() == infer::MatcherType::Image
+    {
+        return PreviewKind::Image;
+    }
+    if extension == "svg" && !data.truncated {
+        return PreviewKind::Svg;
+    }
+    if data.content.starts_with(PDF_MAGIC) {
+        return PreviewKind::Pdf;
+    }
+    if infer::get(&data.content).is_none() && decode_text(data).is_some() {
+        let extension = if extension.is_empty() {
+            "txt".to_string()
+        } else {
+            extension
+        };
+        return PreviewKind::Text { extension };
+    }
+    PreviewKind::Binary
+}
+
+// 预览面板的显示内容
+#[derive(Debug)]
+pub enum PreviewBody {
+    Loading,
+    Image(image::Handle),
+    Svg(svg::Handle),
+    Text {
+        content: text_editor::Content,
+        extension: String,
+    },
+    Pdf(PdfInfo),
+    Hex(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct PreviewPane {
+    pub id: String,
+    pub name: String,
+    pub truncated: bool,
+    pub body: PreviewBody,
+}
+
+impl PreviewPane {
+    pub fn loading(id: String, name: String) -> Self {
+        Self {
+            id,
+            name,
+            truncated: false,
+            body: PreviewBody::Loading,
+        }
+    }
+
+    pub fn show(&mut self, data: PreviewData) {
+        if !data.name.is_empty() {
+            self.name = data.name.clone();
+        }
+        self.truncated = data.truncated;
+        self.body = match classify(&data) {
+            PreviewKind::Image => PreviewBody::Image(image::Handle::from_memory(data.content)),
+            PreviewKind::Svg => PreviewBody::Svg(svg::Handle::from_memory(data.content)),
+            PreviewKind::Text { extension } => PreviewBody::Text {
+                content: text_editor::Content::with_text(decode_text(&data).unwrap_or_default()),
+                extension,
+            },
+            PreviewKind::Pdf => PreviewBody::Pdf(pdf_info(&data.content)),
+            PreviewKind::Binary => PreviewBody::Hex(hex_dump(&data.content)),
+        };
+    }
+}
+
+// 按 UTF-8 解码，被截断时允许末尾有不完整的字符
+pub fn decode_text(data: &PreviewData) -> Option<&str> {
+    match std::str::from_utf8(&data.content) {
+        Ok(text) => Some(text),
+        Err(e) if data.truncated && e.error_len().is_none() => {
+            std::str::from_utf8(&data.content[..e.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    }
+}
+
+// PDF 的基本信息；不渲染页面，只读取文件头中的版本、页数和文档信息中的标题与作者
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PdfInfo {
+    pub version: String,
+    pub pages: Option<usize>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+}
+
+pub fn pdf_info(content: &[u8]) -> PdfInfo {
+    let version = content
+        .strip_prefix(PDF_MAGIC)
+        .unwrap_or_default()
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit() || **byte == b'.')
+        .map(|&byte| byte as char)
+        .collect();
+    PdfInfo {
+        version,
+        pages: pdf_page_count(content),
+        title: pdf_text_field(content, b"/Title"),
+        author: pdf_text_field(content, b"/Author"),
+    }
+}
+
+fn find_all<'a>(data: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    data.windows(needle.len())
+        .enumerate()
+        .filter(move |(_, window)| *window == needle)
+        .map(move |(index, _)| index + needle.len())
+}
+
+fn skip_whitespace(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+// 逐个统计 /Type /Page 对象；页面对象在压缩的对象流中时退而使用页面树的 /Count
+fn pdf_page_count(content: &[u8]) -> Option<usize> {
+    let pages = find_all(content, b"/Type")
+        .filter(|&end| {
+            let value = skip_whitespace(&content[end..]);
+            value.starts_with(b"/Page")
+                && !value
+                    .get(5)
+                    .is_some_and(|byte| byte.is_ascii_alphanumeric())
+        })
+        .count();
+    if pages > 0 {
+        return Some(pages);
+    }
+    find_all(content, b"/Count")
+        .filter_map(|end| {
+            let digits: String = skip_whitespace(&content[end..])
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .map(|&byte| byte as char)
+                .collect();
+            digits.parse().ok()
+        })
+        .max()
+}
+
+// 文档信息中的文本字段，支持字面字符串 (...) 和十六进制字符串 <...>
+fn pdf_text_field(content: &[u8], key: &[u8]) -> Option<String> {
+    find_all(content, key).find_map(|end| {
+        let value = skip_whitespace(&content[end..]);
+        let bytes = match value.first()? {
+            b'(' => literal_string(&value[1..])?,
+            b'<' if value.get(1) != Some(&b'<') => hex_string(&value[1..])?,
+            _ => return None,
+        };
+        let text = decode_pdf_text(&bytes);
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+// 字面字符串：括号可以嵌套，反斜杠转义特殊字符或最多三位八进制数
+fn literal_string(data: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    let mut depth = 0;
+    let mut index = 0;
+    while let Some(&byte) = data.get(index) {
+        index += 1;
+        match byte {
+            b'\\' => {
+                let escaped = *data.get(index)?;
+                index += 1;
+                match escaped {
+                    b'n' => output.push(b'\n'),
+                    b'r' => output.push(b'\r'),
+                    b't' => output.push(b'\t'),
+                    b'b' => output.push(0x08),
+                    b'f' => output.push(0x0c),
+                    b'0'..=b'7' => {
+                        let mut value = u32::from(escaped - b'0');
+                        for _ in 0..2 {
+                            match data.get(index) {
+                                Some(digit @ b'0'..=b'7') => {
+                                    value = value * 8 + u32::from(digit - b'0');
+                                    index += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        output.push(value as u8);
+                    }
+                    b'\r' | b'\n' => {} // 行尾的反斜杠表示续行
+                    other => output.push(other),
+                }
+            }
+            b'(' => {
+                depth += 1;
+                output.push(byte);
+            }
+            b')' if depth == 0 => return Some(output),
+            b')' => {
+                depth -= 1;
+                output.push(byte);
+            }
+            _ => output.push(byte),
+        }
+    }
+    None
+}
+
+fn hex_string(data: &[u8]) -> Option<Vec<u8>> {
+    let end = data.iter().position(|&byte| byte == b'>')?;
+    let mut digits: Vec<u8> = data[..end]
+        .iter()
+        .filter_map(|&byte| (byte as char).to_digit(16).map(|digit| digit as u8))
+        .collect();
+    // 奇数个数字时最后一位补 0
+    if digits.len() % 2 == 1 {
+        digits.push(0);
+    }
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect(),
+    )
+}
+
+// 带 BOM 的按 UTF-16BE 或 UTF-8 解码，其余按单字节编码处理
+fn decode_pdf_text(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+        char::decode_utf16(units)
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    } else if let Some(utf8) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        String::from_utf8_lossy(utf8).into_owned()
+    } else {
+        bytes.iter().map(|&byte| byte as char).collect()
+    }
+}
+
+// 经典的十六进制视图：偏移量、十六进制字节和可打印字符
+pub fn hex_dump(data: &[u8]) -> String {
+    let mut output = String::new();
+    for (line, chunk) in data[..data.len().min(HEX_VIEW_LIMIT)]
+        .chunks(HEX_BYTES_PER_LINE)
+        .enumerate()
+    {
+        let _ = write!(output, "{:08x}  ", line * HEX_BYTES_PER_LINE);
+        for index in 0..HEX_BYTES_PER_LINE {
+            match chunk.get(index) {
+                Some(byte) => {
+                    let _ = write!(output, "{:02x} ", byte);
+                }
+                None => output.push_str("   "),
+            }
+        }
+        output.push(' ');
+        output.extend(chunk.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        }));
+        output.push('\n');
+    }
+    if data.len() > HEX_VIEW_LIMIT {
+        let _ = write!(
+            output,
+            "... {}",
+            t!("hex-view-truncated", limit = HEX_VIEW_LIMIT)
+        );
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(name: &str, content: &[u8], truncated: bool) -> PreviewData {
+        PreviewData {
+            name: name.to_string(),
+            content: content.to_vec(),
+            truncated,
+        }
+    }
+
+    #[test]
+    fn classifies_preview_content() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(classify(&data("a.png", png, false)), PreviewKind::Image);
+        assert_eq!(classify(&data("a.png", png, true)), PreviewKind::Binary);
+        assert_eq!(
+            classify(&data("main.rs", b"fn main() {}", false)),
+            PreviewKind::Text {
+                extension: "rs".into()
+            }
+        );
+        // 截断在多字节字符中间时仍按文本处理
+        let truncated_text = &"预览".as_bytes()[..4];
+        assert!(matches!(
+            classify(&data("", truncated_text, true)),
+            PreviewKind::Text { .. }
+        ));
+        assert_eq!(
+            classify(&data("a.bin", &[0, 159, 146, 150], false)),
+            PreviewKind::Binary
+        );
+        assert_eq!(
+            hex_dump(b"AB\x00"),
+            format!("00000000  41 42 00 {} AB.\n", "   ".repeat(13))
+        );
+
+        let pdf = b"%PDF-1.7\n1 0 obj << /Type /Pages /Kids [2 0 R 3 0 R] /Count 2 >>\n\
+            2 0 obj << /Type /Page >>\n3 0 obj << /Type/Page >>\n\
+            4 0 obj << /Title (Q3 \\(draft\\) report\\051) /Author <FEFF0041006D0079> >>";
+        assert_eq!(classify(&data("scan", pdf, true)), PreviewKind::Pdf);
+        assert_eq!(
+            pdf_info(pdf),
+            PdfInfo {
+                version: "1.7".into(),
+                pages: Some(2),
+                title: Some("Q3 (draft) report)".into()),
+                author: Some("Amy".into()),
+            }
+        );
+        assert_eq!(pdf_info(b"%PDF-1.5 /Count 12").pages, Some(12));
+
+        // 被截断的信封仍能拆开头部，取得元数据中的文件名
+        let content = b"hello world";
+        let metadata = blob_envelope::BlobMetadata::describe("notes.md", content);
+        let wrapped = blob_envelope::wrap(&metadata, content).unwrap();
+        let preview = prepare_preview(None, wrapped[..wrapped.len() - 6].to_vec(), true).unwrap();
+        assert_eq!(preview.name, "notes.md");
+        assert_eq!(preview.content, b"hello");
+    }
+}
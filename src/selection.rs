@@ -0,0 +1,119 @@
+use crate::data::FileEntry;
+use std::collections::HashSet;
+
+// 当前搜索条件下可见的文件 ID，顺序与列表显示一致
+pub fn visible_ids(files: &[FileEntry], query: &str) -> Vec<String> {
+    files
+        .iter()
+        .filter(|file| file.matches_search(query))
+        .map(|file| file.id.clone())
+        .collect()
+}
+
+// 选中或取消全部可见文件，不影响被搜索条件隐藏的已选文件
+pub fn set_visible(selected: &mut HashSet<String>, visible: &[String], checked: bool) {
+    for id in visible {
+        if checked {
+            selected.insert(id.clone());
+        } else {
+            selected.remove(id);
+        }
+    }
+}
+
+pub fn invert_visible(selected: &mut HashSet<String>, visible: &[String]) {
+    for id in visible {
+        if !selected.remove(id) {
+            selected.insert(id.clone());
+        }
+    }
+}
+
+// Shift 点击：把锚点到目标之间（含两端）的可见文件都设为目标复选框的新状态。
+// 锚点已不可见时只切换目标本身
+pub fn select_range(
+    selected: &mut HashSet<String>,
+    visible: &[String],
+    anchor: &str,
+    target: &str,
+    checked: bool,
+) {
+    let position = |id: &str| visible.iter().position(|v| v == id);
+    match (position(anchor), position(target)) {
+        (Some(a), Some(b)) => set_visible(selected, &visible[a.min(b)..=a.max(b)], checked),
+        _ => set_visible(selected, &[target.to_string()], checked),
+    }
+}
+
+pub fn all_visible_selected(selected: &HashSet<String>, visible: &[String]) -> bool {
+    !visible.is_empty() && visible.iter().all(|id| selected.contains(id))
+}
+
+// 选择汇总栏显示的数量和总大小；旧记录可能没有大小
+#[derive(Debug, Default, PartialEq)]
+pub struct SelectionSummary {
+    pub count: usize,
+    pub total_size: u64,
+    pub unknown_sizes: usize,
+}
+
+pub fn summarize(files: &[FileEntry], selected: &HashSet<String>) -> SelectionSummary {
+    let mut summary = SelectionSummary::default();
+    for file in files.iter().filter(|f| selected.contains(&f.id)) {
+        summary.count += 1;
+        match file.size {
+            Some(size) => summary.total_size += size,
+            None => summary.unknown_sizes += 1,
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_and_invert_only_touch_visible_files() {
+        let mut files: Vec<FileEntry> = ["a.txt", "b.png", "c.txt", "d.txt", "e.png"]
+            .iter()
+            .map(|name| FileEntry::new(format!("id-{}", name), name.to_string()))
+            .collect();
+        files[0].size = Some(10);
+        files[2].size = Some(5);
+
+        let all = visible_ids(&files, "");
+        let texts = visible_ids(&files, "TXT");
+        assert_eq!(texts, vec!["id-a.txt", "id-c.txt", "id-d.txt"]);
+
+        let mut selected = HashSet::new();
+        select_range(&mut selected, &all, "id-d.txt", "id-b.png", true);
+        assert_eq!(selected.len(), 3);
+        select_range(&mut selected, &all, "id-a.txt", "id-c.txt", false);
+        assert_eq!(selected, HashSet::from(["id-d.txt".to_string()]));
+
+        // 锚点被搜索隐藏时只切换目标
+        select_range(&mut selected, &texts, "id-e.png", "id-a.txt", true);
+        assert_eq!(selected.len(), 2);
+
+        selected.insert("id-e.png".into());
+        invert_visible(&mut selected, &texts);
+        assert_eq!(
+            selected,
+            HashSet::from(["id-c.txt".to_string(), "id-e.png".to_string()])
+        );
+        assert!(!all_visible_selected(&selected, &texts));
+        set_visible(&mut selected, &texts, true);
+        assert!(all_visible_selected(&selected, &texts));
+        assert!(!all_visible_selected(&selected, &[]));
+
+        assert_eq!(
+            summarize(&files, &selected),
+            SelectionSummary {
+                count: 4,
+                total_size: 15,
+                unknown_sizes: 2,
+            }
+        );
+    }
+}
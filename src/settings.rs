@@ -0,0 +1,148 @@
+use crate::download::ConflictPolicy;
+use crate::i18n::Language;
+use crate::theme::DEFAULT_THEME;
+use crate::trash::DEFAULT_RETENTION_DAYS;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_AGGREGATOR_URL: &str = "https://aggregator.testnet.walrus.atalma.io";
+pub const DEFAULT_PUBLISHER_URL: &str = "https://publisher.walrus-01.tududes.com";
+
+// 持久化的用户设置，每个工作区一份，保存在工作区目录的 settings.json 中
+// 所有字段都有默认值，旧版设置文件缺少的字段会自动补齐
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct AppSettings {
+    pub network: NetworkProfile,
+    pub sync: SyncSettings,
+    pub upload: UploadSettings,
+    pub download: DownloadSettings,
+    pub display: DisplaySettings,
+    pub trash: TrashSettings,
+    pub chain: ChainSettings,
+}
+
+// Walrus 网络配置：读取使用的聚合器和上传使用的发布器
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkProfile {
+    pub aggregator_url: String,
+    pub publisher_url: String,
+}
+
+impl Default for NetworkProfile {
+    fn default() -> Self {
+        Self {
+            aggregator_url: DEFAULT_AGGREGATOR_URL.into(),
+            publisher_url: DEFAULT_PUBLISHER_URL.into(),
+        }
+    }
+}
+
+// 多设备同步设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncSettings {
+    pub enabled: bool,
+    pub pointer_path: String, // 记录最新快照 ID 的指针文件（例如共享目录中的文件）
+    pub interval_minutes: u64,
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pointer_path: String::new(),
+            interval_minutes: 10,
+        }
+    }
+}
+
+// 上传设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UploadSettings {
+    pub wrap_metadata: bool, // 把文件名、类型等元数据和内容一起上传，按 ID 下载时可恢复
+    pub default_epochs: u32, // 新加入暂存列表的文件默认的存储周期数
+    pub encrypt: bool,       // 上传前用随机文件密钥加密内容
+    pub deletable: bool,     // 以可删除方式存储，到期前可以从 Walrus 删除
+    pub snapshot_epochs: u32, // 配置快照（手动上传和同步）与分享清单的存储周期数
+}
+
+impl Default for UploadSettings {
+    fn default() -> Self {
+        Self {
+            wrap_metadata: false,
+            default_epochs: 1,
+            encrypt: false,
+            deletable: false,
+            snapshot_epochs: 5,
+        }
+    }
+}
+
+// 下载设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DownloadSettings {
+    pub conflict_policy: ConflictPolicy, // 目标文件已存在时的默认处理方式
+}
+
+// 界面显示设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DisplaySettings {
+    pub grid_view: bool, // 以缩略图网格而不是列表显示文件
+}
+
+// 应用级偏好，所有工作区共用，保存在数据目录的 preferences.json 中
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub language: Language, // 界面语言，默认跟随系统
+    pub theme: String,      // 主题名称：内置主题或 themes/ 目录中的自定义主题
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            theme: DEFAULT_THEME.into(),
+        }
+    }
+}
+
+// 回收站设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrashSettings {
+    pub retention_days: u32, // 删除的记录在回收站中保留的天数，0 表示永久保留
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self {
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+// 链上操作设置：删除 blob 等需要签名的操作通过 walrus 命令行客户端完成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChainSettings {
+    pub walrus_cli: String,    // walrus 可执行文件，默认在 PATH 中查找
+    pub walrus_config: String, // walrus 客户端配置文件，留空时使用其默认配置
+    pub sui_address: String,   // 上传时 blob 对象发送到的 Sui 地址，留空时由发布器持有
+    pub keystore_path: String, // 可选的本地 Sui 密钥库，只用于列出其中的地址
+}
+
+impl Default for ChainSettings {
+    fn default() -> Self {
+        Self {
+            walrus_cli: "walrus".into(),
+            walrus_config: String::new(),
+            sui_address: String::new(),
+            keystore_path: String::new(),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::i18n::t;
+use qrcode::{Color, QrCode};
+
+// 分享对话框的状态
+#[derive(Debug, Clone)]
+pub struct ShareDialog {
+    pub id: String,
+    pub name: String,
+    pub include_name: bool, // 链接中是否附带文件名提示
+    pub encrypted: bool,    // 加密上传的文件，聚合器只能返回密文，不生成链接和二维码
+    pub url: String,
+    pub qr: Option<QrMatrix>,
+}
+
+// 二维码的模块矩阵，按行存储，true 表示深色模块
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrMatrix {
+    pub width: usize,
+    pub modules: Vec<bool>,
+}
+
+impl QrMatrix {
+    pub fn encode(text: &str) -> Result<Self, String> {
+        let code =
+            QrCode::new(text.as_bytes()).map_err(|e| t!("qr-code-error", error = e.to_string()))?;
+        Ok(Self {
+            width: code.width(),
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|color| color == Color::Dark)
+                .collect(),
+        })
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_share_links() {
+        let qr =
+            QrMatrix::encode("https://aggregator.example/v1/blobs/abc?filename=a.txt").unwrap();
+        assert_eq!(qr.modules.len(), qr.width * qr.width);
+        // 左上角的定位图案
+        assert!(qr.is_dark(0, 0));
+        assert!(!qr.is_dark(1, 1));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::data::parse_tags;
+use crate::i18n::t;
+use std::fs;
+use std::path::PathBuf;
+
+// Walrus 单次最多可预付的存储周期数
+pub const MAX_EPOCHS: u32 = 53;
+
+// 上传暂存列表中的文件，名称、标签和存储周期在上传前都可以修改
+#[derive(Debug, Clone, PartialEq)]
+pub struct StagedUpload {
+    pub path: PathBuf,
+    pub size: Option<u64>,
+    pub name: String,
+    pub tags: String,   // 逗号分隔的标签输入
+    pub epochs: String, // 存储周期输入，上传时校验
+}
+
+// 校验后实际提交上传的参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadJob {
+    pub path: PathBuf,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub epochs: u32,
+}
+
+impl StagedUpload {
+    pub fn new(path: PathBuf, default_epochs: u32) -> Self {
+        let size = fs::metadata(&path).ok().map(|metadata| metadata.len());
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        Self {
+            path,
+            size,
+            name,
+            tags: String::new(),
+            epochs: default_epochs.to_string(),
+        }
+    }
+
+    pub fn to_job(&self) -> Result<UploadJob, String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(t!(
+                "empty-staged-name",
+                path = self.path.display().to_string()
+            ));
+        }
+        Ok(UploadJob {
+            path: self.path.clone(),
+            name: name.to_string(),
+            tags: parse_tags(&self.tags),
+            epochs: parse_epochs(&self.epochs)?,
+        })
+    }
+}
+
+pub fn parse_epochs(input: &str) -> Result<u32, String> {
+    match input.trim().parse::<u32>() {
+        Ok(epochs) if (1..=MAX_EPOCHS).contains(&epochs) => Ok(epochs),
+        _ => Err(t!(
+            "invalid-epochs",
+            max = MAX_EPOCHS,
+            input = format!("{:?}", input)
+        )),
+    }
+}
+
+// 以易读的单位显示文件大小
+pub fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = size as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", size)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staged_uploads_are_validated() {
+        let mut staged = StagedUpload::new(PathBuf::from("missing/photo.png"), 3);
+        assert_eq!(staged.name, "photo.png");
+        assert_eq!(staged.size, None);
+
+        staged.tags = "旅行, 2024,旅行".into();
+        let job = staged.to_job().unwrap();
+        assert_eq!(job.epochs, 3);
+        assert_eq!(job.tags, ["旅行", "2024"]);
+
+        staged.epochs = "0".into();
+        assert!(staged.to_job().is_err());
+        staged.epochs = "54".into();
+        assert!(staged.to_job().is_err());
+        staged.epochs = "53".into();
+        staged.name = "  ".into();
+        assert!(staged.to_job().is_err());
+
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+    }
+}
@@ -0,0 +1,263 @@
+use crate::config_history::{ConfigSnapshot, SnapshotRecord, fetch_snapshot, publish_snapshot};
+use crate::data::FileEntry;
+use crate::i18n::t;
+use crate::walrus_api::WalrusApi;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// 指针文件内容：记录所有设备共享的最新配置快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncPointer {
+    pub latest: String,
+    pub updated_at: String,
+    pub entry_count: usize,
+}
+
+// 本设备的同步状态，base 是上一次同步完成时的文件列表，用作三方合并的共同祖先
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncState {
+    pub last_synced_id: Option<String>,
+    pub last_synced_at: Option<String>,
+    pub base: Vec<FileEntry>,
+}
+
+// 本地和远程都修改了同一条记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncConflict {
+    pub id: String,
+    pub local: Option<FileEntry>,  // None 表示本地已删除
+    pub remote: Option<FileEntry>, // None 表示远程已删除
+    pub kept: Option<FileEntry>,   // 合并后保留的记录
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeOutcome {
+    pub files: Vec<FileEntry>,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+// 一次同步的结果
+#[derive(Debug, Clone)]
+pub struct SyncOutcome {
+    pub local_at_start: Vec<FileEntry>, // 同步开始时的本地列表，用于合并同步期间的本地改动
+    pub merged: Vec<FileEntry>,
+    pub conflicts: Vec<SyncConflict>,
+    pub synced_id: Option<String>,
+    pub published: Option<SnapshotRecord>, // 本次是否发布了新快照
+}
+
+fn by_id(entries: &[FileEntry]) -> HashMap<&str, &FileEntry> {
+    entries
+        .iter()
+        .map(|entry| (entry.id.as_str(), entry))
+        .collect()
+}
+
+// 以 base 为共同祖先对本地和远程列表做三方合并
+// 只有一方改动的记录直接采用该方的结果；双方改动不同时记为冲突：
+// 一方删除一方修改时保留修改后的记录，双方都修改时保留本地记录
+pub fn three_way_merge(
+    base: &[FileEntry],
+    local: &[FileEntry],
+    remote: &[FileEntry],
+) -> MergeOutcome {
+    let base_by_id = by_id(base);
+    let local_by_id = by_id(local);
+    let remote_by_id = by_id(remote);
+
+    // 保持本地顺序，远程新增的记录按远程顺序追加在后面
+    let mut order: Vec<&str> = Vec::new();
+    let mut seen = HashSet::new();
+    for entry in local.iter().chain(remote.iter()).chain(base.iter()) {
+        if seen.insert(entry.id.as_str()) {
+            order.push(entry.id.as_str());
+        }
+    }
+
+    let mut outcome = MergeOutcome::default();
+    for id in order {
+        let b = base_by_id.get(id).copied();
+        let l = local_by_id.get(id).copied();
+        let r = remote_by_id.get(id).copied();
+        let kept = if l == r || r == b {
+            l
+        } else if l == b {
+            r
+        } else {
+            let kept = match (l, r) {
+                (Some(l), _) => Some(l),
+                (None, r) => r,
+            };
+            outcome.conflicts.push(SyncConflict {
+                id: id.to_string(),
+                local: l.cloned(),
+                remote: r.cloned(),
+                kept: kept.cloned(),
+            });
+            kept
+        };
+        if let Some(entry) = kept {
+            outcome.files.push(entry.clone());
+        }
+    }
+    outcome
+}
+
+pub fn read_pointer(path: &Path) -> Result<Option<SyncPointer>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| t!("read-sync-pointer-failed", error = e.to_string()))?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| t!("parse-sync-pointer-failed", error = e.to_string()))
+}
+
+// 先写入临时文件再重命名，避免其他设备读到写了一半的指针
+pub fn write_pointer(path: &Path, pointer: &SyncPointer) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(pointer)
+        .map_err(|e| t!("serialize-sync-pointer-failed", error = e.to_string()))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| t!("create-pointer-dir-failed", error = e.to_string()))?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, json)
+        .map_err(|e| t!("write-sync-pointer-failed", error = e.to_string()))?;
+    fs::rename(&tmp_path, path).map_err(|e| t!("write-sync-pointer-failed", error = e.to_string()))
+}
+
+// 执行一次同步：读取指针找到最新快照，与本地三方合并，必要时发布新快照并更新指针
+pub async fn run_sync(
+    walrus_api: WalrusApi,
+    pointer_path: PathBuf,
+    passphrase: String,
+    state: SyncState,
+    local: Vec<FileEntry>,
+    epochs: u32,
+) -> Result<SyncOutcome, String> {
+    let pointer = read_pointer(&pointer_path)?;
+
+    let (remote, remote_id) = match &pointer {
+        Some(pointer) if Some(&pointer.latest) == state.last_synced_id.as_ref() => {
+            // 远程没有新快照，上次同步的结果就是远程内容
+            (state.base.clone(), Some(pointer.latest.clone()))
+        }
+        Some(pointer) => {
+            let snapshot =
+                fetch_snapshot(&walrus_api, pointer.latest.clone(), passphrase.clone()).await?;
+            (snapshot.files, Some(pointer.latest.clone()))
+        }
+        None => (Vec::new(), None),
+    };
+
+    let merge = if remote_id.is_some() {
+        three_way_merge(&state.base, &local, &remote)
+    } else {
+        // 还没有任何设备发布过，直接发布本地列表
+        MergeOutcome {
+            files: local.clone(),
+            conflicts: Vec::new(),
+        }
+    };
+
+    let mut published = None;
+    let mut synced_id = remote_id.clone();
+    if remote_id.is_none() || merge.files != remote {
+        let snapshot = ConfigSnapshot::new(merge.files.clone(), remote_id);
+        let record = publish_snapshot(&walrus_api, snapshot, passphrase, epochs).await?;
+        write_pointer(
+            &pointer_path,
+            &SyncPointer {
+                latest: record.id.clone(),
+                updated_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                entry_count: record.entry_count,
+            },
+        )?;
+        synced_id = Some(record.id.clone());
+        published = Some(record);
+    }
+
+    Ok(SyncOutcome {
+        local_at_start: local,
+        merged: merge.files,
+        conflicts: merge.conflicts,
+        synced_id,
+        published,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, name: &str) -> FileEntry {
+        FileEntry {
+            id: id.to_string(),
+            name: name.to_string(),
+            uploaded_at: "2024-01-01 00:00:00".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn three_way_merge_combines_one_sided_changes() {
+        let base = vec![entry("a", "a"), entry("b", "b"), entry("c", "c")];
+        // 本地：删除 b，新增 d
+        let local = vec![entry("a", "a"), entry("c", "c"), entry("d", "d")];
+        // 远程：重命名 a，新增 e
+        let remote = vec![
+            entry("a", "a2"),
+            entry("b", "b"),
+            entry("c", "c"),
+            entry("e", "e"),
+        ];
+
+        let outcome = three_way_merge(&base, &local, &remote);
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(
+            outcome.files,
+            vec![
+                entry("a", "a2"),
+                entry("c", "c"),
+                entry("d", "d"),
+                entry("e", "e")
+            ]
+        );
+    }
+
+    #[test]
+    fn three_way_merge_reports_conflicts() {
+        let base = vec![entry("a", "a"), entry("b", "b")];
+        let local = vec![entry("a", "local"), entry("b", "b2")];
+        let remote = vec![entry("a", "remote")];
+
+        let outcome = three_way_merge(&base, &local, &remote);
+        assert_eq!(outcome.files, vec![entry("a", "local"), entry("b", "b2")]);
+        assert_eq!(outcome.conflicts.len(), 2);
+        assert_eq!(outcome.conflicts[1].remote, None);
+        assert_eq!(outcome.conflicts[1].kept, Some(entry("b", "b2")));
+    }
+
+    #[test]
+    fn pointer_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "walrus_store_pointer_test_{}.json",
+            std::process::id()
+        ));
+        assert_eq!(read_pointer(&path).unwrap(), None);
+        let pointer = SyncPointer {
+            latest: "blob".into(),
+            updated_at: "2024-01-01 00:00:00".into(),
+            entry_count: 3,
+        };
+        write_pointer(&path, &pointer).unwrap();
+        assert_eq!(read_pointer(&path).unwrap(), Some(pointer));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,232 @@
+use iced::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::RwLock;
+
+pub const DEFAULT_THEME: &str = "cyberpunk";
+
+// 界面使用的一组颜色，所有自定义样式都从当前调色板取色
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Palette {
+    #[serde(with = "hex_color")]
+    pub background: Color, // 窗口和面板背景
+    #[serde(with = "hex_color")]
+    pub foreground: Color, // 正文文字
+    #[serde(with = "hex_color")]
+    pub accent: Color, // 按钮、边框和次要文字
+    #[serde(with = "hex_color")]
+    pub highlight: Color, // 悬停和聚焦
+    #[serde(with = "hex_color")]
+    pub danger: Color, // 删除按钮和错误信息
+    #[serde(with = "hex_color")]
+    pub surface: Color, // 输入框和禁用按钮的底色
+    #[serde(with = "hex_color")]
+    pub on_accent: Color, // 按钮上的文字
+    #[serde(with = "hex_color")]
+    pub on_highlight: Color, // 悬停按钮上的文字
+    #[serde(with = "hex_color")]
+    pub muted: Color, // 占位符和禁用文字
+}
+
+// 主题文件的格式：名称加调色板的全部颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedTheme {
+    pub name: String,
+    #[serde(flatten)]
+    pub palette: Palette,
+}
+
+const fn rgb(hex: u32) -> Color {
+    Color::from_rgb(
+        ((hex >> 16) & 0xFF) as f32 / 255.0,
+        ((hex >> 8) & 0xFF) as f32 / 255.0,
+        (hex & 0xFF) as f32 / 255.0,
+    )
+}
+
+pub const CYBERPUNK: Palette = Palette {
+    background: rgb(0x08081A),
+    foreground: rgb(0x00FF00),
+    accent: rgb(0x00C8FF),
+    highlight: rgb(0xBF00FF),
+    danger: rgb(0xFF3333),
+    surface: rgb(0x202030),
+    on_accent: rgb(0x000000),
+    on_highlight: rgb(0xFFFFFF),
+    muted: rgb(0x808080),
+};
+
+const DARK: Palette = Palette {
+    background: rgb(0x1E1E24),
+    foreground: rgb(0xE6E6E6),
+    accent: rgb(0x4A90D9),
+    highlight: rgb(0x6BA8F0),
+    danger: rgb(0xE05252),
+    surface: rgb(0x2C2C34),
+    on_accent: rgb(0xFFFFFF),
+    on_highlight: rgb(0xFFFFFF),
+    muted: rgb(0x8A8A8A),
+};
+
+const LIGHT: Palette = Palette {
+    background: rgb(0xF5F5F7),
+    foreground: rgb(0x1F1F24),
+    accent: rgb(0x2F6FD6),
+    highlight: rgb(0x1B4FA8),
+    danger: rgb(0xC62828),
+    surface: rgb(0xFFFFFF),
+    on_accent: rgb(0xFFFFFF),
+    on_highlight: rgb(0xFFFFFF),
+    muted: rgb(0x7A7A7A),
+};
+
+const HIGH_CONTRAST: Palette = Palette {
+    background: rgb(0x000000),
+    foreground: rgb(0xFFFFFF),
+    accent: rgb(0xFFFF00),
+    highlight: rgb(0x00FFFF),
+    danger: rgb(0xFF4040),
+    surface: rgb(0x000000),
+    on_accent: rgb(0x000000),
+    on_highlight: rgb(0x000000),
+    muted: rgb(0xC0C0C0),
+};
+
+pub fn builtin_themes() -> Vec<NamedTheme> {
+    [
+        (DEFAULT_THEME, CYBERPUNK),
+        ("dark", DARK),
+        ("light", LIGHT),
+        ("high-contrast", HIGH_CONTRAST),
+    ]
+    .into_iter()
+    .map(|(name, palette)| NamedTheme {
+        name: name.to_string(),
+        palette,
+    })
+    .collect()
+}
+
+// 内置主题在前，名称与内置主题相同的自定义主题会被忽略
+pub fn merge_user_themes(user_themes: Vec<NamedTheme>) -> Vec<NamedTheme> {
+    let mut themes = builtin_themes();
+    for theme in user_themes {
+        if themes.iter().any(|t| t.name == theme.name) {
+            eprintln!("主题 {} 与已有主题重名，已忽略", theme.name);
+        } else {
+            themes.push(theme);
+        }
+    }
+    themes
+}
+
+// 按名称查找主题，找不到时（例如自定义主题文件已被删除）使用默认主题
+pub fn find_theme(themes: &[NamedTheme], name: &str) -> Palette {
+    themes
+        .iter()
+        .find(|theme| theme.name == name)
+        .map(|theme| theme.palette)
+        .unwrap_or(CYBERPUNK)
+}
+
+impl Palette {
+    pub fn is_dark(&self) -> bool {
+        let c = self.background;
+        0.299 * c.r + 0.587 * c.g + 0.114 * c.b < 0.5
+    }
+
+    // 供 pick_list、滚动条等内置组件使用的 iced 主题
+    pub fn to_iced_theme(self, name: &str) -> iced::Theme {
+        iced::Theme::custom(
+            name.to_string(),
+            iced::theme::Palette {
+                background: self.background,
+                text: self.foreground,
+                primary: self.accent,
+                success: self.highlight,
+                danger: self.danger,
+            },
+        )
+    }
+}
+
+// 样式在绘制时读取当前调色板，切换主题后下一帧即生效
+static CURRENT_PALETTE: RwLock<Palette> = RwLock::new(CYBERPUNK);
+
+pub fn set_palette(palette: Palette) {
+    *CURRENT_PALETTE.write().unwrap_or_else(|e| e.into_inner()) = palette;
+}
+
+pub fn palette() -> Palette {
+    *CURRENT_PALETTE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+// 主题文件中的颜色写作 "#RRGGBB" 或 "#RRGGBBAA"
+mod hex_color {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let [r, g, b, a] = color.into_rgba8();
+        let hex = if a == 0xFF {
+            format!("#{:02X}{:02X}{:02X}", r, g, b)
+        } else {
+            format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+        };
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse(&text).ok_or_else(|| serde::de::Error::custom(format!("无效的颜色: {}", text)))
+    }
+
+    pub fn parse(text: &str) -> Option<Color> {
+        let hex = text.trim().strip_prefix('#')?;
+        if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let alpha = if hex.len() == 8 { channel(6)? } else { 0xFF };
+        Some(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            alpha as f32 / 255.0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_themes_are_parsed_from_json() {
+        let json = serde_json::to_string(&NamedTheme {
+            name: "mine".into(),
+            palette: LIGHT,
+        })
+        .unwrap();
+        assert!(json.contains("\"background\":\"#F5F5F7\""));
+        let theme: NamedTheme = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            theme.palette.background.into_rgba8(),
+            [0xF5, 0xF5, 0xF7, 0xFF]
+        );
+
+        assert!(serde_json::from_str::<NamedTheme>(&json.replace("#F5F5F7", "red")).is_err());
+        assert_eq!(hex_color::parse("#00000080").unwrap().a, 128.0 / 255.0);
+        assert!(hex_color::parse("#12345").is_none());
+
+        let themes = merge_user_themes(vec![
+            theme,
+            NamedTheme {
+                name: DEFAULT_THEME.into(),
+                palette: LIGHT,
+            },
+        ]);
+        assert_eq!(themes.len(), 5);
+        assert_eq!(find_theme(&themes, "mine"), LIGHT);
+        assert_eq!(find_theme(&themes, "missing"), CYBERPUNK);
+        assert!(CYBERPUNK.is_dark() && !LIGHT.is_dark());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::data::FileEntry;
+use crate::file_management::get_thumbnail_dir;
+use crate::i18n::t;
+use crate::walrus_api::validate_blob_id;
+use image::ImageFormat;
+use std::path::{Path, PathBuf};
+
+pub const THUMBNAIL_SIZE: u32 = 128;
+// 同时在后台获取的缩略图数量
+pub const MAX_PENDING_THUMBNAILS: usize = 4;
+
+const IMAGE_EXTENSIONS: [&str; 9] = [
+    "png", "jpg", "jpeg", "gif", "webp", "bmp", "ico", "tif", "tiff",
+];
+
+// 网格视图中每个文件的缩略图状态
+#[derive(Debug, Clone)]
+pub enum ThumbnailStatus {
+    Pending,
+    Ready(iced::widget::image::Handle),
+    Unavailable, // 不是图片或获取失败，不再重试
+}
+
+// 根据上传时记录的类型或文件扩展名判断是否为图片
+pub fn is_image_entry(entry: &FileEntry) -> bool {
+    match &entry.mime {
+        Some(mime) => mime.starts_with("image/") && mime != "image/svg+xml",
+        None => Path::new(&entry.name)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .is_some_and(|extension| IMAGE_EXTENSIONS.contains(&extension.as_str())),
+    }
+}
+
+// blob ID 直接用作缓存文件名，无效的 ID 不缓存
+pub fn thumbnail_path(blob_id: &str) -> Option<PathBuf> {
+    let blob_id = validate_blob_id(blob_id).ok()?;
+    Some(get_thumbnail_dir().join(format!("{}.png", blob_id)))
+}
+
+pub fn cached_thumbnail(blob_id: &str) -> Option<PathBuf> {
+    thumbnail_path(blob_id).filter(|path| path.exists())
+}
+
+// 缩小图片并以 PNG 格式保存到缓存目录
+pub fn cache_thumbnail(blob_id: &str, data: &[u8]) -> Result<PathBuf, String> {
+    let path = thumbnail_path(blob_id).ok_or_else(|| t!("invalid-blob-id", id = blob_id))?;
+    let thumbnail = generate_thumbnail(data)?;
+    thumbnail
+        .save_with_format(&path, ImageFormat::Png)
+        .map_err(|e| t!("save-thumbnail-failed", error = e.to_string()))?;
+    Ok(path)
+}
+
+pub fn generate_thumbnail(data: &[u8]) -> Result<image::DynamicImage, String> {
+    let image = image::load_from_memory(data)
+        .map_err(|e| t!("decode-image-failed", error = e.to_string()))?;
+    Ok(image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbImage};
+    use std::io::Cursor;
+
+    #[test]
+    fn thumbnails_keep_aspect_ratio() {
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgb8(RgbImage::new(512, 256))
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        let thumbnail = generate_thumbnail(&png).unwrap();
+        assert_eq!(thumbnail.dimensions(), (THUMBNAIL_SIZE, THUMBNAIL_SIZE / 2));
+        assert!(generate_thumbnail(b"not an image").is_err());
+
+        let mut entry = FileEntry {
+            name: "photo.JPG".into(),
+            ..Default::default()
+        };
+        assert!(is_image_entry(&entry));
+        entry.mime = Some("application/pdf".into());
+        assert!(!is_image_entry(&entry));
+    }
+}
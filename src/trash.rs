@@ -0,0 +1,151 @@
+use crate::data::FileEntry;
+use crate::i18n::t;
+use chrono::{NaiveDateTime, TimeDelta};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_RETENTION_DAYS: u32 = 30;
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+// 回收站中的记录：原记录加删除时间，保存在工作区目录的 trash.json 中
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashedEntry {
+    #[serde(flatten)]
+    pub entry: FileEntry,
+    pub deleted_at: String,
+}
+
+pub fn now() -> String {
+    chrono::Utc::now().format(TIME_FORMAT).to_string()
+}
+
+// 把记录放入回收站；同一 ID 已在回收站中时用新删除的记录替换
+pub fn trash_entries(
+    trash: &mut Vec<TrashedEntry>,
+    entries: impl IntoIterator<Item = FileEntry>,
+    deleted_at: &str,
+) {
+    for entry in entries {
+        trash.retain(|t| t.entry.id != entry.id);
+        trash.push(TrashedEntry {
+            entry,
+            deleted_at: deleted_at.to_string(),
+        });
+    }
+}
+
+// 把文件列表中指定 ID 的记录移到回收站，返回移动的数量
+pub fn move_to_trash(
+    files: &mut Vec<FileEntry>,
+    trash: &mut Vec<TrashedEntry>,
+    ids: &[String],
+    deleted_at: &str,
+) -> usize {
+    let (removed, kept): (Vec<_>, Vec<_>) = files.drain(..).partition(|f| ids.contains(&f.id));
+    *files = kept;
+    let count = removed.len();
+    trash_entries(trash, removed, deleted_at);
+    count
+}
+
+// 把回收站中的记录放回文件列表末尾
+pub fn restore_from_trash(
+    files: &mut Vec<FileEntry>,
+    trash: &mut Vec<TrashedEntry>,
+    id: &str,
+) -> Result<FileEntry, String> {
+    if files.iter().any(|f| f.id == id) {
+        return Err(t!("id-already-listed", id = id));
+    }
+    let index = trash
+        .iter()
+        .position(|t| t.entry.id == id)
+        .ok_or_else(|| t!("trash-entry-not-found", id = id))?;
+    let entry = trash.remove(index).entry;
+    files.push(entry.clone());
+    Ok(entry)
+}
+
+fn parse_time(text: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(text, TIME_FORMAT).ok()
+}
+
+// 过期时间；保留期为 0 表示永久保留，删除时间无法解析的记录也不会过期
+fn expires_at(item: &TrashedEntry, retention_days: u32) -> Option<NaiveDateTime> {
+    if retention_days == 0 {
+        return None;
+    }
+    Some(parse_time(&item.deleted_at)? + TimeDelta::days(retention_days as i64))
+}
+
+// 剩余保留天数，永久保留时为 None
+pub fn days_left(item: &TrashedEntry, retention_days: u32, now: &str) -> Option<i64> {
+    let remaining = expires_at(item, retention_days)? - parse_time(now)?;
+    Some(remaining.num_days().max(0))
+}
+
+fn is_expired(item: &TrashedEntry, retention_days: u32, now: &str) -> bool {
+    match (expires_at(item, retention_days), parse_time(now)) {
+        (Some(expires_at), Some(now)) => now >= expires_at,
+        _ => false,
+    }
+}
+
+// 按给定保留期已过期的记录数，用于缩短保留期前提示
+pub fn count_expired(trash: &[TrashedEntry], retention_days: u32, now: &str) -> usize {
+    trash
+        .iter()
+        .filter(|item| is_expired(item, retention_days, now))
+        .count()
+}
+
+// 清除超过保留期的记录，返回清除的数量
+pub fn purge_expired(trash: &mut Vec<TrashedEntry>, retention_days: u32, now: &str) -> usize {
+    let before = trash.len();
+    trash.retain(|item| !is_expired(item, retention_days, now));
+    before - trash.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deleted_entries_can_be_restored_until_they_expire() {
+        let mut files: Vec<FileEntry> = ["a", "b", "c"]
+            .iter()
+            .map(|id| FileEntry::new(id.to_string(), format!("{}.txt", id)))
+            .collect();
+        let mut trash = Vec::new();
+
+        let ids = vec!["a".to_string(), "c".to_string(), "missing".to_string()];
+        assert_eq!(
+            move_to_trash(&mut files, &mut trash, &ids, "2024-01-01 12:00:00"),
+            2
+        );
+        assert_eq!(files.len(), 1);
+        trash_entries(
+            &mut trash,
+            [FileEntry::new("c".into(), "c2.txt".into())],
+            "2024-01-05 12:00:00",
+        );
+        assert_eq!(trash.len(), 2);
+        assert_eq!(trash[1].entry.name, "c2.txt");
+
+        assert_eq!(days_left(&trash[0], 30, "2024-01-11 12:00:00"), Some(20));
+        assert_eq!(days_left(&trash[0], 0, "2024-01-11 12:00:00"), None);
+        assert_eq!(purge_expired(&mut trash, 7, "2024-01-08 11:59:59"), 0);
+        assert_eq!(count_expired(&trash, 7, "2024-01-08 12:00:00"), 1);
+        assert_eq!(purge_expired(&mut trash, 7, "2024-01-08 12:00:00"), 1);
+        assert_eq!(purge_expired(&mut trash, 0, "2099-01-01 00:00:00"), 0);
+
+        let restored = restore_from_trash(&mut files, &mut trash, "c").unwrap();
+        assert_eq!(restored.name, "c2.txt");
+        assert!(trash.is_empty());
+        assert_eq!(files.len(), 2);
+
+        trash_entries(&mut trash, [files[0].clone()], "2024-01-08 12:00:00");
+        assert!(restore_from_trash(&mut files, &mut trash, "b").is_err());
+        assert!(restore_from_trash(&mut files, &mut trash, "a").is_err());
+        assert_eq!(trash.len(), 1);
+    }
+}
@@ -1,71 +1,111 @@
-use crate::Message;
+use crate::activity::{self, KindFilter, ResultFilter};
+use crate::blob_delete::can_delete;
+use crate::bundle::ShareBundle;
+use crate::commands::{matching_commands, palette_input_id, search_input_id};
+use crate::config_history::{ConfigSnapshot, SnapshotAction};
+use crate::config_merge::{ImportMode, ImportPreview, PendingImport, preview_import};
+use crate::confirm::ConfirmAction;
+use crate::cost::{CostEstimate, PriceStatus, estimate, format_wal};
 use crate::data::FileEntry;
-use iced::widget::{button, checkbox, column, container, row, scrollable, text, text_input};
-use iced::{Color, Element, Length};
+use crate::download::{ConflictPolicy, PendingConflict};
+use crate::i18n::{Language, t};
+use crate::identity::fingerprint;
+use crate::preview::{PreviewBody, PreviewPane};
+use crate::selection::{all_visible_selected, summarize, visible_ids};
+use crate::share::{QrMatrix, ShareDialog};
+use crate::staging::{StagedUpload, format_size, parse_epochs};
+use crate::theme::palette;
+use crate::thumbnail::{THUMBNAIL_SIZE, ThumbnailStatus};
+use crate::trash::{self, days_left};
+use crate::workspace::TransferMode;
+use crate::{Message, WalrusStore};
+use iced::highlighter::{self, Highlighter};
+use iced::widget::{
+    Column, button, canvas, checkbox, column, container, image, pick_list, row, scrollable, svg,
+    text, text_editor, text_input,
+};
+use iced::{Color, Element, Font, Length};
 
 const SPACING: u16 = 10;
 const PADDING: u16 = 10;
 
-// Cyberpunk theme colors
-const CYBER_BACKGROUND: Color = Color::from_rgb(
-    0x08 as f32 / 255.0,
-    0x08 as f32 / 255.0,
-    0x1A as f32 / 255.0,
-); // Dark Blue/Near Black
-const CYBER_FOREGROUND: Color = Color::from_rgb(
-    0x00 as f32 / 255.0,
-    0xFF as f32 / 255.0,
-    0x00 as f32 / 255.0,
-); // Bright Green (Neon)
-const CYBER_ACCENT_BLUE: Color = Color::from_rgb(
-    0x00 as f32 / 255.0,
-    0xC8 as f32 / 255.0,
-    0xFF as f32 / 255.0,
-); // Bright Blue (Neon)
-const CYBER_ACCENT_PURPLE: Color = Color::from_rgb(
-    0xBF as f32 / 255.0,
-    0x00 as f32 / 255.0,
-    0xFF as f32 / 255.0,
-); // Bright Purple (Neon)
-const CYBER_ERROR: Color = Color::from_rgb(
-    0xFF as f32 / 255.0,
-    0x33 as f32 / 255.0,
-    0x33 as f32 / 255.0,
-); // Bright Red
-const CYBER_GREY: Color = Color::from_rgb(
-    0x20 as f32 / 255.0,
-    0x20 as f32 / 255.0,
-    0x30 as f32 / 255.0,
-); // Dark Grey for secondary elements
-
-pub fn view_application<'a>(
-    files: &'a [FileEntry],
-    upload_file_path: &'a str,
-    upload_progress: f32,
-    download_id_input: &'a str,
-    status_message: &'a str,
-    search_input: &'a str,                                 // 添加搜索输入参数
-    selected_files: &'a std::collections::HashSet<String>, // 新增
-) -> Element<'a, Message> {
-    let config_buttons = row![
-        button("导入配置")
+pub fn view_application(app: &WalrusStore) -> Element<'_, Message> {
+    let files = &app.files;
+    let upload_progress = app.upload_progress;
+    let download_id_input = app.download_id_input.as_str();
+    let status_message = app.status_message.as_str();
+    let search_input = app.search_input.as_str();
+    let selected_files = &app.selected_files;
+
+    let mut config_buttons = row![
+        button(text(t!("import-config")))
             .on_press(Message::TriggerImportConfig)
-            .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-        button("导出配置")
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        button(text(t!("export-config")))
             .on_press(Message::TriggerExportConfig)
-            .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-        button("上传配置")
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        button(text(t!("upload-config")))
             .on_press(Message::UploadConfigButtonPressed)
-            .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        button(text(t!("config-history")))
+            .on_press(Message::ToggleConfigHistory)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        button(text(if app.sync_in_progress {
+            t!("syncing-short")
+        } else {
+            t!("sync")
+        }))
+        .on_press(Message::ToggleSyncPanel)
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
     ]
     .spacing(SPACING)
     .padding(PADDING);
+    if app.import_undo.is_some() {
+        config_buttons = config_buttons.push(
+            button(text(t!("undo-import")))
+                .on_press(Message::UndoLastImport)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        );
+    }
 
     let title_bar = container(
         row![
-            text("Walrus云盘")
+            text(t!("app-title"))
                 .size(30)
-                .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+                .style(iced::theme::Text::Color(palette().foreground)),
+            pick_list(
+                app.workspaces.clone(),
+                Some(app.workspace.clone()),
+                Message::WorkspaceSelected
+            ),
+            button(text(t!("workspaces")))
+                .on_press(Message::ToggleWorkspacePanel)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(t!("identity-keys")))
+                .on_press(Message::ToggleIdentityPanel)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(t!("trash-button", count = app.trash.len())))
+                .on_press(Message::ToggleTrashPanel)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(t!("activity-log-button")))
+                .on_press(Message::ToggleActivityPanel)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(t!("open-command-palette")))
+                .on_press(Message::ToggleCommandPalette)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            pick_list(
+                &Language::ALL[..],
+                Some(app.preferences.language),
+                Message::LanguageSelected
+            ),
+            pick_list(
+                app.themes
+                    .iter()
+                    .map(|theme| theme.name.clone())
+                    .collect::<Vec<_>>(),
+                Some(app.preferences.theme.clone()),
+                Message::ThemeSelected
+            ),
             iced::widget::Space::with_width(Length::Fill), // 填充空间，将按钮推到右边
             config_buttons,
         ]
@@ -75,114 +115,151 @@ pub fn view_application<'a>(
     .width(Length::Fill)
     .padding(PADDING)
     .center_y()
-    .style(iced::theme::Container::Custom(Box::new(
-        CyberContainerStyle {
-            background: Some(CYBER_BACKGROUND.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 2.0,
-                color: CYBER_ACCENT_BLUE,
-            },
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().background.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 2.0,
+            color: palette().accent,
         },
-    )));
+    })));
 
     // 上传区域
     let upload_area = container(
         column![
             row![
-                button("选择文件")
+                button(text(t!("choose-files")))
                     .on_press(Message::TriggerFileSelection)
-                    .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-                text_input("文件路径", upload_file_path)
-                    .on_input(|_| Message::NoOp) // 只读
-                    .width(Length::FillPortion(2))
-                    .style(iced::theme::TextInput::Custom(Box::new(
-                        CyberTextInputStyle
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                text(if app.upload_staging.is_empty() {
+                    t!("no-files-chosen")
+                } else {
+                    t!("files-chosen", count = app.upload_staging.len())
+                })
+                .style(iced::theme::Text::Color(palette().foreground)),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::Alignment::Center),
+            view_upload_staging(&app.upload_staging, &app.price_status),
+            view_cost_summary(app),
+            row![
+                button(text(t!("upload-all", count = app.upload_staging.len())))
+                    .on_press(Message::UploadButtonPressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("clear")))
+                    .on_press(Message::ClearStaging)
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
                     ))),
+                checkbox(t!("wrap-metadata"), app.settings.upload.wrap_metadata)
+                    .on_toggle(Message::WrapMetadataToggled),
+                checkbox(t!("encrypt-uploads"), app.settings.upload.encrypt)
+                    .on_toggle(Message::EncryptUploadsToggled),
+                checkbox(t!("deletable-uploads"), app.settings.upload.deletable)
+                    .on_toggle(Message::DeletableUploadsToggled),
             ]
-            .spacing(SPACING),
-            button("上传文件")
-                .on_press(Message::UploadButtonPressed)
-                .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
+            .spacing(SPACING)
+            .align_items(iced::Alignment::Center),
             // 进度条 (占位符)
-            text(format!("上传进度: {:.0}%", upload_progress * 100.0))
-                .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+            text(if app.upload_queue.is_empty() {
+                t!(
+                    "upload-progress",
+                    percent = (upload_progress * 100.0).round()
+                )
+            } else {
+                t!(
+                    "upload-progress-queued",
+                    percent = (upload_progress * 100.0).round(),
+                    count = app.upload_queue.len()
+                )
+            })
+            .style(iced::theme::Text::Color(palette().foreground)),
+            text(t!("drop-hint"))
+                .size(13)
+                .style(iced::theme::Text::Color(palette().accent)),
         ]
         .spacing(SPACING)
         .padding(PADDING)
         .width(Length::Fill),
     )
-    .style(iced::theme::Container::Custom(Box::new(
-        CyberContainerStyle {
-            background: Some(CYBER_GREY.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ACCENT_BLUE,
-            },
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().surface.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 1.0,
+            color: palette().accent,
         },
-    )))
+    })))
     .padding(PADDING)
     .width(Length::Fill);
 
     // 文件搜索输入框
-    let search_input_widget = text_input("搜索文件...", search_input)
-        .on_input(Message::SearchInputChanged)
-        .padding(PADDING)
-        .width(Length::Fill)
-        .style(iced::theme::TextInput::Custom(Box::new(
-            CyberTextInputStyle,
-        )));
+    let search_input_widget = row![
+        text_input(&t!("search-files"), search_input)
+            .id(search_input_id())
+            .on_input(Message::SearchInputChanged)
+            .padding(PADDING)
+            .width(Length::Fill)
+            .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle,))),
+        button(text(if app.settings.display.grid_view {
+            t!("list-view")
+        } else {
+            t!("grid-view")
+        }))
+        .on_press(Message::ToggleGridView)
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+    ]
+    .spacing(SPACING)
+    .align_items(iced::Alignment::Center);
 
     // 文件列表区域
+    let filtered_files = files
+        .iter()
+        .filter(|file| file.matches_search(search_input))
+        .collect::<Vec<_>>();
+
+    let visible = visible_ids(files, search_input);
+    let all_visible_selected = all_visible_selected(selected_files, &visible);
     let file_list_header = container(
         row![
-            text("").width(Length::Fixed(20.0)), // 复选框的占位
-            text("文件名")
+            checkbox("", all_visible_selected)
+                .on_toggle(Message::SelectAllVisibleToggled)
+                .width(Length::Fixed(20.0))
+                .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle))),
+            text(t!("file-name"))
                 .width(Length::FillPortion(3))
-                .style(iced::theme::Text::Color(CYBER_ACCENT_BLUE)),
-            text("文件 ID")
+                .style(iced::theme::Text::Color(palette().accent)),
+            text(t!("file-id"))
                 .width(Length::FillPortion(2))
-                .style(iced::theme::Text::Color(CYBER_ACCENT_BLUE)),
-            text("上传时间")
+                .style(iced::theme::Text::Color(palette().accent)),
+            text(t!("uploaded-at"))
                 .width(Length::FillPortion(2))
-                .style(iced::theme::Text::Color(CYBER_ACCENT_BLUE)),
-            text("操作")
+                .style(iced::theme::Text::Color(palette().accent)),
+            text(t!("actions"))
                 .width(Length::FillPortion(2))
-                .style(iced::theme::Text::Color(CYBER_ACCENT_BLUE)),
+                .style(iced::theme::Text::Color(palette().accent)),
         ]
         .spacing(SPACING)
         .padding(PADDING),
     )
-    .style(iced::theme::Container::Custom(Box::new(
-        CyberContainerStyle {
-            background: Some(CYBER_GREY.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ACCENT_PURPLE,
-            },
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().surface.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 1.0,
+            color: palette().highlight,
         },
-    )));
-
-    let filtered_files = files
-        .iter()
-        .filter(|file| {
-            file.name
-                .to_lowercase()
-                .contains(&search_input.to_lowercase())
-                || file
-                    .id
-                    .to_lowercase()
-                    .contains(&search_input.to_lowercase())
-        })
-        .collect::<Vec<_>>();
+    })));
 
-    let file_list_items: Vec<Element<'a, Message>> = filtered_files
+    let file_list_items: Vec<Element<'_, Message>> = filtered_files
         .iter()
         .map(|file_ref| {
             let file_id_clone = file_ref.id.clone();
-            let file_name_clone = file_ref.name.clone();
+            let file_name_clone = if file_ref.tags.is_empty() {
+                file_ref.name.clone()
+            } else {
+                format!("{}  #{}", file_ref.name, file_ref.tags.join(" #"))
+            };
             let uploaded_at_clone = file_ref.uploaded_at.clone();
             let display_id_clone = if file_id_clone.len() > 10 {
                 format!("{}...", &file_id_clone[0..10])
@@ -198,31 +275,45 @@ pub fn view_application<'a>(
                 Message::FileSelectedForBatch(file_id_clone.clone(), is_checked)
             })
             .width(Length::Fixed(20.0))
-            .style(iced::theme::Checkbox::Custom(Box::new(CyberCheckboxStyle)));
+            .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle)));
 
             container(
                 row![
                     checkbox,
                     text(file_name_clone)
                         .width(Length::FillPortion(3))
-                        .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+                        .style(iced::theme::Text::Color(palette().foreground)),
                     text(display_id_clone)
                         .width(Length::FillPortion(2))
-                        .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+                        .style(iced::theme::Text::Color(palette().foreground)),
                     text(uploaded_at_clone)
                         .width(Length::FillPortion(2))
-                        .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+                        .style(iced::theme::Text::Color(palette().foreground)),
                     row![
-                        button("复制 ID")
+                        button(text(t!("copy-id")))
                             .on_press(Message::CopyIdToClipboard(file_ref.id.clone()))
-                            .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-                        button("下载")
+                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                        button(text(t!("share")))
+                            .on_press(Message::ShareButtonPressed(file_ref.id.clone()))
+                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                        button(text(t!("preview")))
+                            .on_press(Message::PreviewButtonPressed(file_ref.id.clone()))
+                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                        button(text(t!("download")))
                             .on_press(Message::DownloadButtonPressed(file_ref.id.clone()))
-                            .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-                        button("删除")
+                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                        button(text(t!("forget")))
                             .on_press(Message::DeleteButtonPressed(file_ref.id.clone()))
                             .style(iced::theme::Button::Custom(Box::new(
-                                CyberDestructiveButtonStyle
+                                DestructiveButtonStyle
+                            ))),
+                        button(text(t!("delete-from-walrus")))
+                            .on_press_maybe(
+                                can_delete(file_ref, &app.settings.chain.sui_address)
+                                    .then(|| Message::DeleteFromWalrusPressed(file_ref.id.clone()))
+                            )
+                            .style(iced::theme::Button::Custom(Box::new(
+                                DestructiveButtonStyle
                             ))),
                     ]
                     .spacing(SPACING)
@@ -231,16 +322,14 @@ pub fn view_application<'a>(
                 .spacing(SPACING)
                 .padding(PADDING),
             )
-            .style(iced::theme::Container::Custom(Box::new(
-                CyberContainerStyle {
-                    background: Some(CYBER_BACKGROUND.into()),
-                    border: iced::Border {
-                        radius: 5.0.into(),
-                        width: 1.0,
-                        color: CYBER_ACCENT_PURPLE,
-                    },
+            .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+                background: Some(palette().background.into()),
+                border: iced::Border {
+                    radius: 5.0.into(),
+                    width: 1.0,
+                    color: palette().highlight,
                 },
-            )))
+            })))
             .into()
         })
         .collect();
@@ -249,26 +338,82 @@ pub fn view_application<'a>(
         .width(Length::Fill)
         .height(Length::FillPortion(6));
 
-    let file_list_area = column![file_list_header, file_list_scrollable,]
-        .spacing(SPACING)
-        .padding(PADDING)
-        .width(Length::Fill);
+    let file_list_area = if app.settings.display.grid_view {
+        column![view_file_grid(app, &filtered_files)]
+    } else {
+        column![file_list_header, file_list_scrollable,]
+    }
+    .spacing(SPACING)
+    .padding(PADDING)
+    .width(Length::Fill);
+
+    // 选择汇总栏：反选当前可见文件，并显示选中数量和总大小
+    let summary = summarize(files, selected_files);
+    let mut summary_text = t!(
+        "selection-summary",
+        count = summary.count,
+        total = files.len(),
+        size = format_size(summary.total_size)
+    );
+    if summary.unknown_sizes > 0 {
+        summary_text.push_str(&t!(
+            "selection-unknown-sizes",
+            count = summary.unknown_sizes
+        ));
+    }
+    let selection_bar = row![
+        button(text(t!("invert-selection")))
+            .on_press(Message::InvertSelection)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        text(summary_text).style(iced::theme::Text::Color(palette().accent)),
+    ]
+    .spacing(SPACING)
+    .padding([0, PADDING])
+    .align_items(iced::Alignment::Center);
 
     // 批量操作区域
     let batch_actions_area = if selected_files.is_empty() {
         row![]
     } else {
         row![
-            button("批量删除")
+            button(text(t!("batch-delete")))
                 .on_press(Message::BatchDeleteButtonPressed)
                 .style(iced::theme::Button::Custom(Box::new(
-                    CyberDestructiveButtonStyle
+                    DestructiveButtonStyle
                 ))),
-            button("批量下载")
+            button(text(t!("batch-download")))
                 .on_press(Message::BatchDownloadButtonPressed)
-                .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(if app.share_recipients.is_empty() {
+                t!("share-selected")
+            } else {
+                t!(
+                    "share-selected-encrypted",
+                    count = app.share_recipients.len()
+                )
+            }))
+            .on_press(Message::ShareSelectionPressed)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            iced::widget::Space::with_width(Length::Fill),
+            pick_list(
+                app.workspaces
+                    .iter()
+                    .filter(|name| **name != app.workspace)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                app.transfer_target.clone(),
+                Message::TransferTargetSelected
+            )
+            .placeholder(t!("target-workspace")),
+            button(text(t!("copy-to-workspace")))
+                .on_press(Message::TransferSelectedEntries(TransferMode::Copy))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            button(text(t!("move-to-workspace")))
+                .on_press(Message::TransferSelectedEntries(TransferMode::Move))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
         ]
         .spacing(SPACING)
+        .align_items(iced::alignment::Alignment::Center)
         .padding(PADDING)
         .width(Length::Fill)
     };
@@ -276,20 +421,44 @@ pub fn view_application<'a>(
     // 下载区域
     let download_area = container(
         column![
-            text_input("输入文件 ID", download_id_input)
+            text_input(&t!("enter-file-id"), download_id_input)
                 .on_input(Message::DownloadInputChanged)
                 .padding(PADDING)
                 .width(Length::Fill)
-                .style(iced::theme::TextInput::Custom(Box::new(
-                    CyberTextInputStyle
-                ))),
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
             row![
-                button("从 ID 下载")
+                button(text(t!("download-by-id")))
                     .on_press(Message::DownloadFromInputButtonPressed)
-                    .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
-                button("加载配置")
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("load-config")))
                     .on_press(Message::LoadConfigFromIdButtonPressed)
-                    .style(iced::theme::Button::Custom(Box::new(CyberButtonStyle))),
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("open-bundle")))
+                    .on_press(Message::OpenBundlePressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                config_passphrase_input(&app.config_passphrase),
+                text(t!("when-file-exists")).style(iced::theme::Text::Color(palette().foreground)),
+                pick_list(
+                    &ConflictPolicy::ALL[..],
+                    Some(app.settings.download.conflict_policy),
+                    Message::ConflictPolicyChanged
+                ),
+            ]
+            .align_items(iced::Alignment::Center)
+            .spacing(SPACING)
+            .width(Length::Fill),
+            row![
+                text_input(&t!("file-name-optional"), &app.add_blob_name)
+                    .on_input(Message::AddBlobNameChanged)
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                text_input(&t!("tags-optional"), &app.add_blob_tags)
+                    .on_input(Message::AddBlobTagsChanged)
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                button(text(t!("add-to-list")))
+                    .on_press(Message::AddExistingBlobPressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
             ]
             .spacing(SPACING)
             .width(Length::Fill),
@@ -298,16 +467,14 @@ pub fn view_application<'a>(
         .padding(PADDING)
         .width(Length::Fill),
     )
-    .style(iced::theme::Container::Custom(Box::new(
-        CyberContainerStyle {
-            background: Some(CYBER_GREY.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ACCENT_BLUE,
-            },
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().surface.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 1.0,
+            color: palette().accent,
         },
-    )))
+    })))
     .padding(PADDING)
     .width(Length::Fill);
 
@@ -316,229 +483,1802 @@ pub fn view_application<'a>(
             .size(16)
             .horizontal_alignment(iced::alignment::Horizontal::Center)
             .vertical_alignment(iced::alignment::Vertical::Center)
-            .style(iced::theme::Text::Color(CYBER_FOREGROUND)),
+            .style(iced::theme::Text::Color(palette().foreground)),
     )
     .width(Length::Fill)
     .padding(PADDING)
     .center_x()
-    .style(iced::theme::Container::Custom(Box::new(
-        CyberContainerStyle {
-            background: Some(CYBER_BACKGROUND.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 2.0,
-                color: CYBER_ACCENT_BLUE,
-            },
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().background.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 2.0,
+            color: palette().accent,
         },
-    )));
-
-    column![
-        title_bar,
-        upload_area,
-        search_input_widget, // 添加搜索输入框
-        file_list_area,
-        batch_actions_area, // 添加批量操作区域
-        download_area,
-        status_bar,
-    ]
-    .spacing(SPACING)
-    .width(Length::Fill)
-    .height(Length::Fill)
-    .into()
-}
-
-struct CyberContainerStyle {
-    background: Option<iced::Background>,
-    border: iced::Border,
-}
+    })));
 
-impl iced::widget::container::StyleSheet for CyberContainerStyle {
-    type Style = iced::Theme;
-
-    fn appearance(&self, _style: &Self::Style) -> iced::widget::container::Appearance {
-        iced::widget::container::Appearance {
-            background: self.background,
-            border: self.border,
-            text_color: Some(CYBER_FOREGROUND),
-            shadow: iced::Shadow::default(), // 添加 shadow 字段
-        }
+    if app.drop_hover {
+        return column![title_bar, view_drop_overlay(), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
-}
-
-struct CyberButtonStyle;
-
-impl iced::widget::button::StyleSheet for CyberButtonStyle {
-    type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(CYBER_ACCENT_BLUE.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ACCENT_BLUE,
-            },
-            text_color: Color::BLACK,
-            ..Default::default()
-        }
+    if let Some(query) = &app.command_palette {
+        return column![title_bar, view_command_palette(query), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn hovered(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(CYBER_ACCENT_PURPLE.into()),
-            text_color: Color::WHITE,
-            ..self.active(_style)
-        }
+    if let Some(action) = &app.pending_confirmation {
+        return column![title_bar, view_confirmation(app, action), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn pressed(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            border: iced::Border {
-                width: 2.0,
-                ..self.active(_style).border
-            },
-            ..self.active(_style)
-        }
+    if app.pending_encrypted_config.is_some() {
+        return column![
+            title_bar,
+            view_decrypt_config_prompt(&app.config_passphrase),
+            status_bar,
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
     }
 
-    fn disabled(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(CYBER_GREY.into()),
-            text_color: Color::from_rgb(0.5, 0.5, 0.5),
-            ..self.active(_style)
-        }
+    if let Some(conflict) = app.download_conflicts.first() {
+        return column![
+            title_bar,
+            view_download_conflict(
+                conflict,
+                app.download_conflicts.len() - 1,
+                app.remember_conflict_choice,
+            ),
+            status_bar,
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
     }
-}
-
-struct CyberDestructiveButtonStyle;
-
-impl iced::widget::button::StyleSheet for CyberDestructiveButtonStyle {
-    type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(CYBER_ERROR.into()),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ERROR,
-            },
-            text_color: Color::BLACK,
-            ..Default::default()
-        }
+    if let Some(dialog) = &app.share_dialog {
+        return column![title_bar, view_share_dialog(dialog), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn hovered(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(Color::from_rgb(1.0, 0.2, 0.2).into()), // Lighter red on hover
-            text_color: Color::WHITE,
-            ..self.active(_style)
-        }
+    if let Some((bundle_id, bundle)) = &app.open_bundle {
+        return column![title_bar, view_bundle(bundle_id, bundle), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn pressed(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            border: iced::Border {
-                width: 2.0,
-                ..self.active(_style).border
-            },
-            ..self.active(_style)
-        }
+    if let Some(pending) = &app.pending_import {
+        return column![title_bar, view_import_preview(files, pending), status_bar,]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn disabled(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
-        iced::widget::button::Appearance {
-            background: Some(CYBER_GREY.into()),
-            text_color: Color::from_rgb(0.5, 0.5, 0.5),
-            ..self.active(_style)
-        }
+    if let Some((snapshot_id, snapshot)) = &app.snapshot_diff {
+        return column![
+            title_bar,
+            view_snapshot_diff(files, snapshot_id, snapshot),
+            status_bar,
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
     }
-}
 
-struct CyberTextInputStyle;
-
-impl iced::widget::text_input::StyleSheet for CyberTextInputStyle {
-    type Style = iced::Theme;
-
-    fn active(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
-        iced::widget::text_input::Appearance {
-            background: CYBER_GREY.into(),
-            border: iced::Border {
-                radius: 5.0.into(),
-                width: 1.0,
-                color: CYBER_ACCENT_BLUE,
-            },
-            icon_color: CYBER_FOREGROUND,
-        }
+    if app.show_workspace_panel {
+        return column![title_bar, view_workspace_panel(app), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn focused(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
-        iced::widget::text_input::Appearance {
-            border: iced::Border {
-                color: CYBER_ACCENT_PURPLE,
-                ..self.active(_style).border
-            },
-            ..self.active(_style)
-        }
+    if app.show_activity_panel {
+        return column![title_bar, view_activity_panel(app), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
-
-    fn hovered(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
-        iced::widget::text_input::Appearance {
-            border: iced::Border {
-                color: CYBER_ACCENT_PURPLE,
-                ..self.active(_style).border
-            },
-            ..self.focused(_style)
-        }
+    if app.show_trash_panel {
+        return column![title_bar, view_trash_panel(app), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn placeholder_color(&self, _style: &Self::Style) -> Color {
-        Color::from_rgb(0.5, 0.5, 0.5)
+    if app.show_identity_panel {
+        return column![title_bar, view_identity_panel(app), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn value_color(&self, _style: &Self::Style) -> Color {
-        CYBER_FOREGROUND
+    if app.show_sync_panel {
+        return column![title_bar, view_sync_panel(app), status_bar]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn disabled_color(&self, _style: &Self::Style) -> Color {
-        Color::from_rgb(0.5, 0.5, 0.5)
+    if app.show_config_history {
+        return column![title_bar, view_config_history(app), status_bar,]
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
     }
 
-    fn selection_color(&self, _style: &Self::Style) -> Color {
-        CYBER_ACCENT_BLUE
+    // 打开预览时在文件列表右侧显示预览面板
+    let file_list_area: Element<'_, Message> = match &app.preview {
+        Some(preview) => row![
+            file_list_area,
+            view_preview_pane(
+                preview,
+                app.files.iter().find(|f| f.id == preview.id),
+                &app.settings.chain.sui_address,
+            )
+        ]
+        .spacing(SPACING)
+        .height(Length::FillPortion(6))
+        .into(),
+        None => file_list_area.into(),
+    };
+
+    column![
+        title_bar,
+        upload_area,
+        search_input_widget, // 添加搜索输入框
+        selection_bar,
+        file_list_area,
+        batch_actions_area, // 添加批量操作区域
+        download_area,
+        status_bar,
+    ]
+    .spacing(SPACING)
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
+// 配置密码输入框，上传配置时用于加密，加载配置时用于解密
+fn config_passphrase_input(passphrase: &str) -> Element<'_, Message> {
+    text_input(&t!("config-passphrase"), passphrase)
+        .on_input(Message::ConfigPassphraseChanged)
+        .secure(true)
+        .padding(PADDING)
+        .width(Length::Fill)
+        .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle)))
+        .into()
+}
+
+// 加载到加密配置时提示输入密码
+fn view_decrypt_config_prompt(passphrase: &str) -> Element<'_, Message> {
+    dialog_container(
+        column![
+            text(t!("remote-config-encrypted"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text_input(&t!("config-passphrase"), passphrase)
+                .on_input(Message::ConfigPassphraseChanged)
+                .on_submit(Message::DecryptConfigButtonPressed)
+                .secure(true)
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            row![
+                button(text(t!("decrypt")))
+                    .on_press(Message::DecryptConfigButtonPressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("cancel")))
+                    .on_press(Message::CancelDecryptConfig)
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 下载目标已存在时询问用户如何处理，remaining 为之后还在等待的冲突数量
+fn view_download_conflict(
+    conflict: &PendingConflict,
+    remaining: usize,
+    remember: bool,
+) -> Element<'_, Message> {
+    let mut actions = row![].spacing(SPACING);
+    for policy in ConflictPolicy::RESOLUTIONS {
+        actions = actions.push(
+            button(text(policy.to_string()))
+                .on_press(Message::ResolveDownloadConflict(policy))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        );
     }
+    dialog_container(
+        column![
+            text(t!("download-exists-title"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(t!(
+                "conflict-file",
+                path = conflict.path.display().to_string(),
+                size = conflict.content.len()
+            ))
+            .style(iced::theme::Text::Color(palette().accent)),
+            actions,
+            checkbox(t!("remember-conflict-choice"), remember)
+                .on_toggle(Message::RememberConflictChoiceToggled)
+                .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle))),
+            text(if remaining > 0 {
+                t!("conflicts-remaining", count = remaining)
+            } else {
+                String::new()
+            })
+            .style(iced::theme::Text::Color(palette().foreground)),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
 
-    fn disabled(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
-        iced::widget::text_input::Appearance {
-            background: Color::from_rgb(0.1, 0.1, 0.1).into(),
+// 分享包内容：接收方可以下载全部文件或导入到自己的文件列表
+fn view_bundle<'a>(bundle_id: &'a str, bundle: &'a ShareBundle) -> Element<'a, Message> {
+    let entries = bundle.entries.iter().map(|entry| {
+        row![
+            text(&entry.name)
+                .width(Length::FillPortion(3))
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(entry.size.map(format_size).unwrap_or_else(|| "?".into()))
+                .width(Length::FillPortion(1))
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(&entry.id)
+                .size(13)
+                .width(Length::FillPortion(3))
+                .style(iced::theme::Text::Color(palette().accent)),
+        ]
+        .spacing(SPACING)
+        .into()
+    });
+    let summary = match bundle.total_size() {
+        Some(size) => t!(
+            "bundle-summary-with-size",
+            count = bundle.entries.len(),
+            size = format_size(size),
+            created_at = bundle.created_at.as_str()
+        ),
+        None => t!(
+            "bundle-summary",
+            count = bundle.entries.len(),
+            created_at = bundle.created_at.as_str()
+        ),
+    };
+    dialog_container(
+        column![
+            text(t!("bundle-title", id = bundle_id))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(summary).style(iced::theme::Text::Color(palette().accent)),
+            scrollable(column(entries).spacing(SPACING / 2)).height(Length::Fill),
+            row![
+                button(text(t!("download-all")))
+                    .on_press(Message::TriggerBundleDownloadSelection)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("import-to-list")))
+                    .on_press(Message::ImportBundlePressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("close")))
+                    .on_press(Message::CloseBundle)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 分享对话框：聚合器下载链接和对应的二维码
+fn view_share_dialog(dialog: &ShareDialog) -> Element<'_, Message> {
+    let close_button = button(text(t!("close")))
+        .on_press(Message::CloseShareDialog)
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle)));
+    // 聚合器链接只能下载密文，加密文件改用按接收人加密的分享包
+    if dialog.encrypted {
+        return dialog_container(
+            column![
+                text(t!("share-title", name = dialog.name.as_str()))
+                    .size(20)
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(t!("share-encrypted-use-bundle"))
+                    .style(iced::theme::Text::Color(palette().danger)),
+                close_button,
+            ]
+            .spacing(SPACING)
+            .padding(PADDING),
+        );
+    }
+    let qr_code: Element<'_, Message> = match &dialog.qr {
+        Some(qr) => canvas(QrCodeView(qr))
+            .width(Length::Fixed(QR_CODE_SIZE))
+            .height(Length::Fixed(QR_CODE_SIZE))
+            .into(),
+        None => text(t!("qr-code-failed"))
+            .style(iced::theme::Text::Color(palette().danger))
+            .into(),
+    };
+    dialog_container(
+        column![
+            text(t!("share-title", name = dialog.name.as_str()))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text_input(&t!("share-link"), &dialog.url)
+                .on_input(|_| Message::NoOp) // 只读
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            checkbox(t!("include-name-in-link"), dialog.include_name)
+                .on_toggle(Message::ShareIncludeNameToggled)
+                .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle))),
+            row![
+                button(text(t!("copy-link")))
+                    .on_press(Message::CopyShareLink)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                close_button,
+            ]
+            .spacing(SPACING),
+            text(t!("scan-qr-hint")).style(iced::theme::Text::Color(palette().accent)),
+            qr_code,
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 破坏性操作的确认对话框，删除记录时列出受影响的文件名
+fn view_confirmation<'a>(app: &'a WalrusStore, action: &ConfirmAction) -> Element<'a, Message> {
+    const MAX_LISTED: usize = 10;
+    let names: Vec<&str> = match action {
+        ConfirmAction::DeleteEntries(ids) => app
+            .files
+            .iter()
+            .filter(|f| ids.contains(&f.id))
+            .map(|f| f.name.as_str())
+            .collect(),
+        ConfirmAction::PurgeTrash(ids) => app
+            .trash
+            .iter()
+            .filter(|t| ids.contains(&t.entry.id))
+            .map(|t| t.entry.name.as_str())
+            .collect(),
+        _ => Vec::new(),
+    };
+    let mut listed = column![].spacing(SPACING / 2);
+    for name in names.iter().take(MAX_LISTED) {
+        listed = listed.push(text(*name).style(iced::theme::Text::Color(palette().foreground)));
+    }
+    if names.len() > MAX_LISTED {
+        listed = listed.push(
+            text(t!("confirm-more-entries", count = names.len() - MAX_LISTED))
+                .style(iced::theme::Text::Color(palette().muted)),
+        );
+    }
+
+    dialog_container(
+        column![
+            text(t!("confirm-title"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(action.prompt(app.settings.trash.retention_days))
+                .style(iced::theme::Text::Color(palette().accent)),
+            scrollable(listed).height(Length::Shrink),
+            row![
+                button(text(action.confirm_label()))
+                    .on_press(Message::ConfirmPendingAction)
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+                button(text(t!("cancel")))
+                    .on_press(Message::CancelPendingAction)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 回收站：按删除时间从新到旧列出记录，可恢复或永久删除
+fn view_trash_panel(app: &WalrusStore) -> Element<'_, Message> {
+    let now = trash::now();
+    let retention_days = app.settings.trash.retention_days;
+    let mut items = column![].spacing(SPACING);
+    for item in app.trash.iter().rev() {
+        let remaining = match days_left(item, retention_days, &now) {
+            Some(days) => t!("days-left", days = days),
+            None => t!("kept-forever"),
+        };
+        items = items.push(
+            row![
+                text(&item.entry.name)
+                    .width(Length::FillPortion(3))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(t!("deleted-at", time = item.deleted_at.as_str()))
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::Text::Color(palette().accent)),
+                text(remaining)
+                    .width(Length::FillPortion(1))
+                    .style(iced::theme::Text::Color(palette().muted)),
+                button(text(t!("restore")))
+                    .on_press(Message::RestoreFromTrash(item.entry.id.clone()))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("delete-permanently")))
+                    .on_press(Message::PurgeFromTrashPressed(item.entry.id.clone()))
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+        );
+    }
+    let list: Element<'_, Message> = if app.trash.is_empty() {
+        text(t!("trash-is-empty"))
+            .style(iced::theme::Text::Color(palette().muted))
+            .into()
+    } else {
+        scrollable(items).height(Length::Fill).into()
+    };
+
+    dialog_container(
+        column![
+            text(t!("trash-title", count = app.trash.len()))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            row![
+                text(t!("trash-retention-days"))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text_input("30", &app.trash_retention_input)
+                    .on_input(Message::TrashRetentionChanged)
+                    .on_submit(Message::TrashRetentionSubmitted)
+                    .padding(PADDING)
+                    .width(Length::Fixed(80.0))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                iced::widget::Space::with_width(Length::Fill),
+                button(text(t!("empty-trash")))
+                    .on_press(Message::EmptyTrashPressed)
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+                button(text(t!("close")))
+                    .on_press(Message::ToggleTrashPanel)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            list,
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 活动日志：按类型和结果筛选，最新的在前；记录很多时只显示最近的一部分，导出包含全部筛选结果
+fn view_activity_panel(app: &WalrusStore) -> Element<'_, Message> {
+    const MAX_SHOWN: usize = 500;
+    let records = activity::filter(
+        &app.activity,
+        app.activity_kind_filter,
+        app.activity_result_filter,
+    );
+    let mut items = column![].spacing(SPACING / 2);
+    for record in records.iter().take(MAX_SHOWN) {
+        let (result, color) = if record.success {
+            (t!("activity-succeeded"), palette().accent)
+        } else {
+            (t!("activity-failed"), palette().danger)
+        };
+        let duration = record
+            .duration_ms
+            .map(|ms| format!("{:.1} s", ms as f64 / 1000.0))
+            .unwrap_or_default();
+        items = items.push(
+            column![
+                row![
+                    text(&record.at)
+                        .width(Length::Fixed(160.0))
+                        .style(iced::theme::Text::Color(palette().muted)),
+                    text(record.kind.to_string())
+                        .width(Length::Fixed(100.0))
+                        .style(iced::theme::Text::Color(palette().foreground)),
+                    text(result)
+                        .width(Length::Fixed(60.0))
+                        .style(iced::theme::Text::Color(color)),
+                    text(&record.subject)
+                        .width(Length::Fill)
+                        .style(iced::theme::Text::Color(palette().foreground)),
+                    text(duration)
+                        .width(Length::Fixed(70.0))
+                        .style(iced::theme::Text::Color(palette().muted)),
+                    text(record.bytes.map(format_size).unwrap_or_default())
+                        .width(Length::Fixed(80.0))
+                        .style(iced::theme::Text::Color(palette().muted)),
+                ]
+                .spacing(SPACING),
+                text(&record.detail)
+                    .size(13)
+                    .style(iced::theme::Text::Color(palette().muted)),
+            ]
+            .spacing(2),
+        );
+    }
+    let list: Element<'_, Message> = if records.is_empty() {
+        text(t!("activity-empty"))
+            .style(iced::theme::Text::Color(palette().muted))
+            .into()
+    } else {
+        scrollable(items).height(Length::Fill).into()
+    };
+
+    dialog_container(
+        column![
+            text(t!(
+                "activity-title",
+                shown = records.len().min(MAX_SHOWN),
+                count = records.len()
+            ))
+            .size(20)
+            .style(iced::theme::Text::Color(palette().foreground)),
+            row![
+                pick_list(
+                    KindFilter::options(),
+                    Some(app.activity_kind_filter),
+                    Message::ActivityKindFilterChanged,
+                ),
+                pick_list(
+                    &ResultFilter::ALL[..],
+                    Some(app.activity_result_filter),
+                    Message::ActivityResultFilterChanged,
+                ),
+                iced::widget::Space::with_width(Length::Fill),
+                button(text(t!("export-activity")))
+                    .on_press_maybe((!records.is_empty()).then_some(Message::TriggerExportActivity))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("close")))
+                    .on_press(Message::ToggleActivityPanel)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            list,
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 命令面板：输入时模糊筛选命令，回车执行第一项
+fn view_command_palette(query: &str) -> Element<'_, Message> {
+    let commands: Vec<Element<'_, Message>> = matching_commands(query)
+        .into_iter()
+        .map(|command| {
+            button(
+                row![
+                    text(command.label()).width(Length::Fill),
+                    text(command.shortcut().unwrap_or_default()),
+                ]
+                .spacing(SPACING),
+            )
+            .on_press(Message::RunCommand(command))
+            .width(Length::Fill)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle)))
+            .into()
+        })
+        .collect();
+    let command_list: Element<'_, Message> = if commands.is_empty() {
+        text(t!("no-matching-commands"))
+            .style(iced::theme::Text::Color(palette().muted))
+            .into()
+    } else {
+        scrollable(column(commands).spacing(SPACING / 2)).into()
+    };
+    dialog_container(
+        column![
+            text(t!("command-palette"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text_input(&t!("command-palette-placeholder"), query)
+                .id(palette_input_id())
+                .on_input(Message::CommandPaletteInputChanged)
+                .on_submit(Message::CommandPaletteSubmit)
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            command_list,
+            text(t!("command-palette-hint")).style(iced::theme::Text::Color(palette().accent)),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+const QR_CODE_SIZE: f32 = 240.0;
+// 二维码四周保留的空白模块数
+const QR_QUIET_ZONE: usize = 4;
+
+// 在画布上绘制二维码，白底黑块，保证深色主题下也能被扫描
+struct QrCodeView<'a>(&'a QrMatrix);
+
+impl canvas::Program<Message> for QrCodeView<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: iced::Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let qr = self.0;
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        frame.fill_rectangle(iced::Point::ORIGIN, bounds.size(), Color::WHITE);
+        let modules = (qr.width + QR_QUIET_ZONE * 2) as f32;
+        let module_size = bounds.width.min(bounds.height) / modules;
+        for y in 0..qr.width {
+            for x in 0..qr.width {
+                if qr.is_dark(x, y) {
+                    frame.fill_rectangle(
+                        iced::Point::new(
+                            (x + QR_QUIET_ZONE) as f32 * module_size,
+                            (y + QR_QUIET_ZONE) as f32 * module_size,
+                        ),
+                        iced::Size::new(module_size, module_size),
+                        Color::BLACK,
+                    );
+                }
+            }
+        }
+        vec![frame.into_geometry()]
+    }
+}
+
+// 按当前价格估算暂存文件的费用；价格未知、大小未知或周期无效时为 None
+fn staged_estimate(staged: &StagedUpload, price_status: &PriceStatus) -> Option<CostEstimate> {
+    let PriceStatus::Ready(info) = price_status else {
+        return None;
+    };
+    Some(estimate(
+        info,
+        staged.size?,
+        parse_epochs(&staged.epochs).ok()?,
+    ))
+}
+
+// 暂存文件的预计总费用、价格状态和本工作区累计费用
+fn view_cost_summary(app: &WalrusStore) -> Element<'_, Message> {
+    let estimate_line = match &app.price_status {
+        PriceStatus::Ready(_) if app.upload_staging.is_empty() => t!("price-ready"),
+        PriceStatus::Ready(_) => {
+            let estimates: Vec<CostEstimate> = app
+                .upload_staging
+                .iter()
+                .filter_map(|staged| staged_estimate(staged, &app.price_status))
+                .collect();
+            t!(
+                "cost-estimate-total",
+                encoded = format_size(estimates.iter().map(|e| e.encoded_size).sum()),
+                cost = format_wal(estimates.iter().map(|e| e.frost).sum()),
+                skipped = app.upload_staging.len() - estimates.len()
+            )
+        }
+        PriceStatus::Loading => t!("price-loading"),
+        PriceStatus::Unknown => t!("price-unknown"),
+        PriceStatus::Failed(e) => t!("price-unavailable", error = e.as_str()),
+    };
+    row![
+        text(estimate_line)
+            .size(13)
+            .width(Length::Fill)
+            .style(iced::theme::Text::Color(palette().foreground)),
+        button(text(t!("refresh-price")))
+            .on_press_maybe(
+                (!matches!(app.price_status, PriceStatus::Loading))
+                    .then_some(Message::RefreshPricePressed)
+            )
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        text(t!(
+            "workspace-spend",
+            cost = format_wal(app.spend.total_frost),
+            count = app.spend.uploads
+        ))
+        .size(13)
+        .style(iced::theme::Text::Color(palette().accent)),
+    ]
+    .spacing(SPACING)
+    .align_items(iced::Alignment::Center)
+    .into()
+}
+
+// 上传暂存列表：每个文件一行，可修改目标文件名、标签和存储周期，并显示按当前价格估算的费用
+fn view_upload_staging<'a>(
+    staging: &'a [StagedUpload],
+    price_status: &PriceStatus,
+) -> Element<'a, Message> {
+    if staging.is_empty() {
+        return column![].into();
+    }
+    let rows = staging.iter().enumerate().map(|(index, staged)| {
+        let cost = staged_estimate(staged, price_status)
+            .map(|estimate| format_wal(estimate.frost))
+            .unwrap_or_else(|| "—".into());
+        row![
+            text(staged.path.display().to_string())
+                .size(13)
+                .width(Length::FillPortion(3))
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text(staged.size.map(format_size).unwrap_or_else(|| "?".into()))
+                .size(13)
+                .width(Length::Fixed(80.0))
+                .style(iced::theme::Text::Color(palette().foreground)),
+            text_input(&t!("file-name"), &staged.name)
+                .on_input(move |name| Message::StagedNameChanged(index, name))
+                .width(Length::FillPortion(2))
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            text_input(&t!("tags"), &staged.tags)
+                .on_input(move |tags| Message::StagedTagsChanged(index, tags))
+                .width(Length::FillPortion(2))
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            text_input(&t!("epochs"), &staged.epochs)
+                .on_input(move |epochs| Message::StagedEpochsChanged(index, epochs))
+                .width(Length::Fixed(60.0))
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            text(cost)
+                .size(13)
+                .width(Length::Fixed(100.0))
+                .style(iced::theme::Text::Color(palette().foreground)),
+            button(text(t!("remove")))
+                .on_press(Message::RemoveStagedFile(index))
+                .style(iced::theme::Button::Custom(Box::new(
+                    DestructiveButtonStyle
+                ))),
+        ]
+        .spacing(SPACING)
+        .align_items(iced::Alignment::Center)
+        .into()
+    });
+    scrollable(column(rows).spacing(SPACING / 2))
+        .height(Length::Shrink)
+        .into()
+}
+
+// 文件拖到窗口上方时显示的放置区域
+fn view_drop_overlay<'a>() -> Element<'a, Message> {
+    container(
+        column![
+            text(t!("drop-release"))
+                .size(32)
+                .style(iced::theme::Text::Color(palette().accent)),
+            text(t!("drop-details")).style(iced::theme::Text::Color(palette().foreground)),
+        ]
+        .spacing(SPACING)
+        .align_items(iced::Alignment::Center),
+    )
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().surface.into()),
+        border: iced::Border {
+            radius: 10.0.into(),
+            width: 3.0,
+            color: palette().accent,
+        },
+    })))
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .center_x()
+    .center_y()
+    .into()
+}
+
+// 缩略图网格视图：每行固定数量的卡片，图片显示缩略图，其他文件显示类型占位
+fn view_file_grid<'a>(app: &'a WalrusStore, files: &[&'a FileEntry]) -> Element<'a, Message> {
+    const CARDS_PER_ROW: usize = 5;
+    let thumbnail_size = THUMBNAIL_SIZE as f32;
+
+    let card = |file: &'a FileEntry| -> Element<'a, Message> {
+        let thumbnail: Element<'a, Message> = match app.thumbnails.get(&file.id) {
+            Some(ThumbnailStatus::Ready(handle)) => image(handle.clone())
+                .width(Length::Fixed(thumbnail_size))
+                .height(Length::Fixed(thumbnail_size))
+                .into(),
+            status => {
+                let label = match status {
+                    Some(ThumbnailStatus::Pending) => t!("loading"),
+                    _ => file
+                        .name
+                        .rsplit_once('.')
+                        .map(|(_, extension)| extension.to_uppercase())
+                        .unwrap_or_else(|| t!("file")),
+                };
+                container(text(label).style(iced::theme::Text::Color(palette().accent)))
+                    .width(Length::Fixed(thumbnail_size))
+                    .height(Length::Fixed(thumbnail_size))
+                    .center_x()
+                    .center_y()
+                    .into()
+            }
+        };
+        let file_id = file.id.clone();
+        container(
+            column![
+                thumbnail,
+                row![
+                    checkbox("", app.selected_files.contains(&file.id))
+                        .on_toggle(move |is_checked| {
+                            Message::FileSelectedForBatch(file_id.clone(), is_checked)
+                        })
+                        .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle))),
+                    text(&file.name)
+                        .size(14)
+                        .style(iced::theme::Text::Color(palette().foreground)),
+                ]
+                .align_items(iced::Alignment::Center),
+                row![
+                    button(text(t!("preview")))
+                        .on_press(Message::PreviewButtonPressed(file.id.clone()))
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                    button(text(t!("download")))
+                        .on_press(Message::DownloadButtonPressed(file.id.clone()))
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                ]
+                .spacing(SPACING / 2),
+            ]
+            .spacing(SPACING / 2)
+            .align_items(iced::Alignment::Center),
+        )
+        .padding(PADDING)
+        .width(Length::FillPortion(1))
+        .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+            background: Some(palette().background.into()),
+            border: iced::Border {
+                radius: 5.0.into(),
+                width: 1.0,
+                color: palette().highlight,
+            },
+        })))
+        .into()
+    };
+
+    let rows = files.chunks(CARDS_PER_ROW).map(|chunk| {
+        let mut cards = row![].spacing(SPACING);
+        for file in chunk {
+            cards = cards.push(card(file));
+        }
+        // 最后一行不足时用空白补齐，保持卡片宽度一致
+        for _ in chunk.len()..CARDS_PER_ROW {
+            cards = cards.push(iced::widget::Space::with_width(Length::FillPortion(1)));
+        }
+        cards.into()
+    });
+    scrollable(column(rows).spacing(SPACING))
+        .width(Length::Fill)
+        .height(Length::FillPortion(6))
+        .into()
+}
+
+// 地址输入无效时提示；有效或留空时说明上传的 blob 对象归谁持有
+fn sui_address_hint(app: &WalrusStore) -> (String, Color) {
+    let input = app.sui_address_input.trim();
+    if input.is_empty() {
+        (t!("sui-address-unset"), palette().foreground)
+    } else if input.to_ascii_lowercase() != app.settings.chain.sui_address {
+        (t!("invalid-sui-address", address = input), palette().danger)
+    } else {
+        (t!("sui-address-set"), palette().foreground)
+    }
+}
+
+// blob 对象的持有情况：上传时发送到指定地址、由发布器持有，或没有记录对象（已存在的 blob、旧记录）
+fn ownership_text(entry: &FileEntry, own_address: &str) -> String {
+    match (&entry.object_id, &entry.owner) {
+        (Some(_), Some(owner)) if owner == own_address => {
+            t!("owned-by-workspace-address", address = owner.as_str())
+        }
+        (Some(_), Some(owner)) => t!("owned-by", address = owner.as_str()),
+        (Some(_), None) => t!("owned-by-publisher"),
+        (None, _) => t!("blob-object-unknown"),
+    }
+}
+
+// 预览面板：图片直接显示，文本按扩展名语法高亮，其他内容显示十六进制视图
+fn view_preview_pane<'a>(
+    preview: &'a PreviewPane,
+    entry: Option<&FileEntry>,
+    own_address: &str,
+) -> Element<'a, Message> {
+    let body: Element<'_, Message> = match &preview.body {
+        PreviewBody::Loading => text(t!("loading-preview"))
+            .style(iced::theme::Text::Color(palette().foreground))
+            .into(),
+        PreviewBody::Error(e) => text(t!("preview-failed", error = e.as_str()))
+            .style(iced::theme::Text::Color(palette().danger))
+            .into(),
+        PreviewBody::Image(handle) => scrollable(image(handle.clone()).width(Length::Fill))
+            .height(Length::Fill)
+            .into(),
+        PreviewBody::Svg(handle) => svg(handle.clone())
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into(),
+        PreviewBody::Text { content, extension } => text_editor(content)
+            .on_action(Message::PreviewEditorAction)
+            .font(Font::MONOSPACE)
+            .height(Length::Fill)
+            .highlight::<Highlighter>(
+                highlighter::Settings {
+                    theme: if palette().is_dark() {
+                        highlighter::Theme::Base16Ocean
+                    } else {
+                        highlighter::Theme::InspiredGitHub
+                    },
+                    extension: extension.clone(),
+                },
+                |highlight, _theme| highlight.to_format(),
+            )
+            .into(),
+        PreviewBody::Pdf(info) => {
+            let mut lines = vec![t!("pdf-version", version = info.version.as_str())];
+            lines.push(match info.pages {
+                Some(pages) => t!("pdf-pages", count = pages),
+                None => t!("pdf-pages-unknown"),
+            });
+            if let Some(title) = &info.title {
+                lines.push(t!("pdf-title", title = title.as_str()));
+            }
+            if let Some(author) = &info.author {
+                lines.push(t!("pdf-author", author = author.as_str()));
+            }
+            lines.push(t!("pdf-preview-hint"));
+            column(lines.into_iter().map(|line| {
+                text(line)
+                    .style(iced::theme::Text::Color(palette().foreground))
+                    .into()
+            }))
+            .spacing(SPACING / 2)
+            .height(Length::Fill)
+            .into()
+        }
+        PreviewBody::Hex(dump) => scrollable(
+            text(dump)
+                .font(Font::MONOSPACE)
+                .size(13)
+                .style(iced::theme::Text::Color(palette().foreground)),
+        )
+        .height(Length::Fill)
+        .into(),
+    };
+    let mut details = column![].spacing(2);
+    if let Some(entry) = entry {
+        let detail = |line: String| {
+            text(line)
+                .size(13)
+                .style(iced::theme::Text::Color(palette().foreground))
+        };
+        details = details.push(detail(t!("blob-id-detail", id = entry.id.as_str())));
+        if let Some(object_id) = &entry.object_id {
+            details = details.push(detail(t!("blob-object-id", id = object_id.as_str())));
+        }
+        details = details.push(detail(ownership_text(entry, own_address)));
+        if let Some(cost) = entry.cost.filter(|cost| *cost > 0) {
+            details = details.push(detail(t!("blob-cost", cost = format_wal(cost))));
+        }
+    }
+    let title = if preview.truncated {
+        t!("preview-truncated", name = preview.name.as_str())
+    } else {
+        preview.name.clone()
+    };
+    container(
+        column![
+            row![
+                text(title)
+                    .width(Length::Fill)
+                    .style(iced::theme::Text::Color(palette().accent)),
+                button(text(t!("close")))
+                    .on_press(Message::ClosePreview)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::Alignment::Center),
+            details,
+            body,
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+    .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+        background: Some(palette().surface.into()),
+        border: iced::Border {
+            radius: 5.0.into(),
+            width: 1.0,
+            color: palette().highlight,
+        },
+    })))
+    .width(Length::FillPortion(1))
+    .height(Length::Fill)
+    .into()
+}
+
+// 以列表形式展示导入或对比的变更；incoming_label 为对方列表的名称
+fn change_list<'a>(
+    preview: &ImportPreview,
+    incoming_label: &str,
+    show_resolution: bool,
+) -> Column<'a, Message> {
+    let section_title = |label: String| -> Element<'a, Message> {
+        text(label)
+            .style(iced::theme::Text::Color(palette().accent))
+            .into()
+    };
+    let entry_line = |prefix: &str, entry: &FileEntry| -> Element<'a, Message> {
+        text(format!("{} {} ({})", prefix, entry.name, entry.id))
+            .style(iced::theme::Text::Color(palette().foreground))
+            .into()
+    };
+
+    let mut details = column![].spacing(5);
+    details = details.push(section_title(t!(
+        "changes-added",
+        count = preview.added.len()
+    )));
+    for entry in &preview.added {
+        details = details.push(entry_line("+", entry));
+    }
+    details = details.push(section_title(t!(
+        "changes-removed",
+        count = preview.removed.len()
+    )));
+    for entry in &preview.removed {
+        details = details.push(entry_line("-", entry));
+    }
+    details = details.push(section_title(t!(
+        "changes-conflicts",
+        count = preview.conflicts.len()
+    )));
+    for conflict in &preview.conflicts {
+        let mut line = t!(
+            "change-conflict",
+            id = conflict.local.id.as_str(),
+            local = format!("{} ({})", conflict.local.name, conflict.local.uploaded_at),
+            label = incoming_label,
+            incoming = format!(
+                "{} ({})",
+                conflict.incoming.name, conflict.incoming.uploaded_at
+            ),
+        );
+        if show_resolution {
+            let kept = if conflict.keep_incoming {
+                t!("keep-incoming")
+            } else {
+                t!("keep-local")
+            };
+            line = format!("{} -> {}", line, kept);
+        }
+        details = details.push(text(line).style(iced::theme::Text::Color(palette().danger)));
+    }
+    details.push(section_title(t!(
+        "changes-unchanged",
+        count = preview.unchanged
+    )))
+}
+
+// 对话框统一使用的容器样式
+fn dialog_container<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    container(content)
+        .style(iced::theme::Container::Custom(Box::new(PanelStyle {
+            background: Some(palette().surface.into()),
             border: iced::Border {
-                color: Color::from_rgb(0.2, 0.2, 0.2),
+                radius: 5.0.into(),
+                width: 1.0,
+                color: palette().highlight,
+            },
+        })))
+        .padding(PADDING)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+// 导入预览对话框：展示新增、移除和冲突的记录，确认后才会修改本地列表
+fn view_import_preview<'a>(
+    files: &'a [FileEntry],
+    pending: &'a PendingImport,
+) -> Element<'a, Message> {
+    let mode = pending.mode;
+    let preview = preview_import(files, &pending.incoming, mode);
+
+    dialog_container(
+        column![
+            text(t!("import-preview-title", source = pending.source.as_str()))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            row![
+                text(t!("import-mode")).style(iced::theme::Text::Color(palette().accent)),
+                pick_list(&ImportMode::ALL[..], Some(mode), Message::ImportModeChanged),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            scrollable(change_list(&preview, &t!("change-source-import"), true))
+                .height(Length::Fill),
+            row![
+                button(text(t!("confirm-import")))
+                    .on_press(Message::ConfirmImport)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("cancel")))
+                    .on_press(Message::CancelImport)
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 配置历史浏览器：列出已发布的快照，最新的在最上面
+fn view_config_history(app: &WalrusStore) -> Element<'_, Message> {
+    let history = &app.config_history;
+    let short_id = |id: &str| -> String {
+        if id.len() > 10 {
+            format!("{}...", &id[0..10])
+        } else {
+            id.to_string()
+        }
+    };
+
+    let mut records = column![].spacing(SPACING);
+    if history.is_empty() {
+        records = records
+            .push(text(t!("no-snapshots")).style(iced::theme::Text::Color(palette().foreground)));
+    }
+    for record in history.iter().rev() {
+        let previous = record
+            .previous
+            .as_deref()
+            .map(short_id)
+            .unwrap_or_else(|| t!("none"));
+        records = records.push(
+            row![
+                text(&record.created_at)
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(short_id(&record.id))
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(t!("snapshot-file-count", count = record.entry_count))
+                    .width(Length::FillPortion(1))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(t!("snapshot-epochs", count = record.epochs))
+                    .width(Length::FillPortion(1))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(t!("previous-snapshot", id = previous))
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::Text::Color(palette().accent)),
+                button(text(t!("copy-id")))
+                    .on_press(Message::CopyIdToClipboard(record.id.clone()))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("compare")))
+                    .on_press(Message::FetchSnapshot(
+                        record.id.clone(),
+                        SnapshotAction::Diff
+                    ))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("restore")))
+                    .on_press(Message::FetchSnapshot(
+                        record.id.clone(),
+                        SnapshotAction::Restore
+                    ))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+        );
+    }
+
+    dialog_container(
+        column![
+            text(t!("config-history-title", count = history.len()))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            row![
+                text(t!("snapshot-epochs-label")).style(iced::theme::Text::Color(palette().accent)),
+                text_input("5", &app.snapshot_epochs_input)
+                    .on_input(Message::SnapshotEpochsChanged)
+                    .padding(PADDING)
+                    .width(Length::Fixed(100.0))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            scrollable(records).height(Length::Fill),
+            button(text(t!("close")))
+                .on_press(Message::ToggleConfigHistory)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 工作区管理面板：切换、新建、删除工作区，编辑当前工作区的网络配置
+fn view_workspace_panel(app: &WalrusStore) -> Element<'_, Message> {
+    let mut workspaces = column![].spacing(SPACING);
+    for name in &app.workspaces {
+        let is_active = *name == app.workspace;
+        let mut switch = button(text(if is_active {
+            t!("current")
+        } else {
+            t!("switch")
+        }))
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle)));
+        let mut delete = button(text(t!("delete"))).style(iced::theme::Button::Custom(Box::new(
+            DestructiveButtonStyle,
+        )));
+        if !is_active {
+            switch = switch.on_press(Message::WorkspaceSelected(name.clone()));
+            delete = delete.on_press(Message::DeleteWorkspacePressed(name.clone()));
+        }
+        workspaces = workspaces.push(
+            row![
+                text(name)
+                    .width(Length::Fill)
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                switch,
+                delete,
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+        );
+    }
+
+    dialog_container(
+        column![
+            text(t!("workspaces"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            scrollable(workspaces).height(Length::Fill),
+            row![
+                text_input(&t!("new-workspace-name"), &app.new_workspace_name)
+                    .on_input(Message::NewWorkspaceNameChanged)
+                    .on_submit(Message::CreateWorkspacePressed)
+                    .padding(PADDING)
+                    .width(Length::Fill)
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                button(text(t!("create")))
+                    .on_press(Message::CreateWorkspacePressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            text(t!("network-profile-title", name = app.workspace.as_str()))
+                .style(iced::theme::Text::Color(palette().accent)),
+            text_input(&t!("aggregator-url"), &app.network_input.aggregator_url)
+                .on_input(Message::AggregatorUrlChanged)
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            text_input(&t!("publisher-url"), &app.network_input.publisher_url)
+                .on_input(Message::PublisherUrlChanged)
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            text(t!("chain-settings-title")).style(iced::theme::Text::Color(palette().accent)),
+            row![
+                text_input(&t!("walrus-cli-path"), &app.settings.chain.walrus_cli)
+                    .on_input(Message::WalrusCliChanged)
+                    .padding(PADDING)
+                    .width(Length::FillPortion(1))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                text_input(&t!("walrus-config-path"), &app.settings.chain.walrus_config)
+                    .on_input(Message::WalrusConfigChanged)
+                    .padding(PADDING)
+                    .width(Length::FillPortion(2))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            ]
+            .spacing(SPACING),
+            text_input(&t!("sui-address-placeholder"), &app.sui_address_input)
+                .on_input(Message::SuiAddressChanged)
+                .padding(PADDING)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+            {
+                let (hint, color) = sui_address_hint(app);
+                text(hint).size(13).style(iced::theme::Text::Color(color))
+            },
+            row![
+                text_input(
+                    &t!("keystore-path-placeholder"),
+                    &app.settings.chain.keystore_path
+                )
+                .on_input(Message::KeystorePathChanged)
+                .padding(PADDING)
+                .width(Length::Fill)
+                .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                button(text(t!("load-keystore")))
+                    .on_press_maybe(
+                        (!app.settings.chain.keystore_path.trim().is_empty())
+                            .then_some(Message::LoadKeystorePressed)
+                    )
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                pick_list(
+                    app.keystore_addresses.clone(),
+                    app.keystore_addresses
+                        .iter()
+                        .find(|a| **a == app.settings.chain.sui_address)
+                        .cloned(),
+                    Message::SuiAddressChanged,
+                )
+                .placeholder(t!("choose-keystore-address")),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::Alignment::Center),
+            row![
+                button(text(t!("save-network-profile")))
+                    .on_press(Message::SaveNetworkProfilePressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("close")))
+                    .on_press(Message::ToggleWorkspacePanel)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 身份密钥与通讯录：展示本机公钥，管理联系人并选择加密分享的接收人
+fn view_identity_panel(app: &WalrusStore) -> Element<'_, Message> {
+    let own_key = match &app.identity {
+        Some(identity) => column![
+            text(&identity.public_key).style(iced::theme::Text::Color(palette().foreground)),
+            row![
+                text(t!("fingerprint", value = fingerprint(&identity.public_key)))
+                    .width(Length::Fill)
+                    .style(iced::theme::Text::Color(palette().accent)),
+                button(text(t!("copy-public-key")))
+                    .on_press(Message::CopyPublicKey)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+        ]
+        .spacing(SPACING),
+        None => {
+            column![
+                text(t!("identity-unavailable")).style(iced::theme::Text::Color(palette().danger))
+            ]
+        }
+    };
+
+    let mut contacts = column![].spacing(SPACING);
+    if app.contacts.is_empty() {
+        contacts = contacts
+            .push(text(t!("contacts-empty")).style(iced::theme::Text::Color(palette().highlight)));
+    }
+    for contact in &app.contacts {
+        let key = contact.public_key.clone();
+        contacts = contacts.push(
+            row![
+                checkbox(
+                    t!("share-to"),
+                    app.share_recipients.contains(&contact.public_key)
+                )
+                .on_toggle(move |selected| Message::ShareRecipientToggled(key.clone(), selected)),
+                text(&contact.name)
+                    .width(Length::Fixed(150.0))
+                    .style(iced::theme::Text::Color(palette().foreground)),
+                text(fingerprint(&contact.public_key))
+                    .width(Length::Fill)
+                    .style(iced::theme::Text::Color(palette().accent)),
+                button(text(t!("delete")))
+                    .on_press(Message::RemoveContact(contact.public_key.clone()))
+                    .style(iced::theme::Button::Custom(Box::new(
+                        DestructiveButtonStyle
+                    ))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+        );
+    }
+
+    dialog_container(
+        column![
+            text(t!("my-public-key"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            own_key,
+            text(t!("contacts-title", count = app.contacts.len()))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            scrollable(contacts).height(Length::Fill),
+            row![
+                text_input(&t!("contact-name"), &app.new_contact_name)
+                    .on_input(Message::NewContactNameChanged)
+                    .padding(PADDING)
+                    .width(Length::Fixed(200.0))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                text_input(&t!("contact-public-key"), &app.new_contact_key)
+                    .on_input(Message::NewContactKeyChanged)
+                    .on_submit(Message::AddContactPressed)
+                    .padding(PADDING)
+                    .width(Length::Fill)
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                button(text(t!("add")))
+                    .on_press(Message::AddContactPressed)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            button(text(t!("close")))
+                .on_press(Message::ToggleIdentityPanel)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 多设备同步设置面板，同时展示最近一次同步的冲突
+fn view_sync_panel(app: &WalrusStore) -> Element<'_, Message> {
+    let sync = &app.settings.sync;
+    let last_sync = match (
+        &app.sync_state.last_synced_at,
+        &app.sync_state.last_synced_id,
+    ) {
+        (Some(at), Some(id)) => t!("last-synced", at = at.as_str(), id = id.as_str()),
+        _ => t!("never-synced"),
+    };
+    let describe = |entry: &Option<FileEntry>| -> String {
+        entry
+            .as_ref()
+            .map(|entry| format!("{} ({})", entry.name, entry.uploaded_at))
+            .unwrap_or_else(|| t!("deleted"))
+    };
+
+    let mut conflicts = column![
+        text(t!(
+            "last-sync-conflicts",
+            count = app.last_sync_conflicts.len()
+        ))
+        .style(iced::theme::Text::Color(palette().accent))
+    ]
+    .spacing(5);
+    for conflict in &app.last_sync_conflicts {
+        conflicts = conflicts.push(
+            text(t!(
+                "sync-conflict",
+                id = conflict.id.as_str(),
+                local = describe(&conflict.local),
+                remote = describe(&conflict.remote),
+                kept = describe(&conflict.kept)
+            ))
+            .style(iced::theme::Text::Color(palette().danger)),
+        );
+    }
+
+    let mut sync_now = button(text(if app.sync_in_progress {
+        t!("syncing-short")
+    } else {
+        t!("sync-now")
+    }))
+    .style(iced::theme::Button::Custom(Box::new(ButtonStyle)));
+    if !app.sync_in_progress {
+        sync_now = sync_now.on_press(Message::SyncNowPressed);
+    }
+
+    dialog_container(
+        column![
+            text(t!("multi-device-sync"))
+                .size(20)
+                .style(iced::theme::Text::Color(palette().foreground)),
+            checkbox(t!("auto-sync"), sync.enabled)
+                .on_toggle(Message::SyncEnabledToggled)
+                .style(iced::theme::Checkbox::Custom(Box::new(CheckboxStyle))),
+            row![
+                text_input(&t!("sync-pointer-placeholder"), &sync.pointer_path)
+                    .on_input(Message::SyncPointerPathChanged)
+                    .padding(PADDING)
+                    .width(Length::Fill)
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                button(text(t!("choose")))
+                    .on_press(Message::TriggerSyncPointerSelection)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            row![
+                text(t!("sync-interval-minutes")).style(iced::theme::Text::Color(palette().accent)),
+                text_input("10", &app.sync_interval_input)
+                    .on_input(Message::SyncIntervalChanged)
+                    .padding(PADDING)
+                    .width(Length::Fixed(100.0))
+                    .style(iced::theme::TextInput::Custom(Box::new(TextInputStyle))),
+                config_passphrase_input(&app.config_passphrase),
+            ]
+            .spacing(SPACING)
+            .align_items(iced::alignment::Alignment::Center),
+            text(last_sync).style(iced::theme::Text::Color(palette().foreground)),
+            scrollable(conflicts).height(Length::Fill),
+            row![
+                sync_now,
+                button(text(t!("close")))
+                    .on_press(Message::ToggleSyncPanel)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+// 快照对比：以当前文件列表为基准，展示快照中的差异
+fn view_snapshot_diff<'a>(
+    files: &'a [FileEntry],
+    snapshot_id: &'a str,
+    snapshot: &'a ConfigSnapshot,
+) -> Element<'a, Message> {
+    let preview = preview_import(files, &snapshot.files, ImportMode::Replace);
+
+    dialog_container(
+        column![
+            text(t!(
+                "snapshot-diff-title",
+                id = snapshot_id,
+                created_at = snapshot.created_at.as_str()
+            ))
+            .size(20)
+            .style(iced::theme::Text::Color(palette().foreground)),
+            scrollable(change_list(&preview, &t!("change-source-snapshot"), false))
+                .height(Length::Fill),
+            row![
+                button(text(t!("restore-snapshot")))
+                    .on_press(Message::FetchSnapshot(
+                        snapshot_id.to_string(),
+                        SnapshotAction::Restore
+                    ))
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+                button(text(t!("close")))
+                    .on_press(Message::CloseSnapshotDiff)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle))),
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING)
+        .padding(PADDING),
+    )
+}
+
+struct PanelStyle {
+    background: Option<iced::Background>,
+    border: iced::Border,
+}
+
+impl iced::widget::container::StyleSheet for PanelStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> iced::widget::container::Appearance {
+        iced::widget::container::Appearance {
+            background: self.background,
+            border: self.border,
+            text_color: Some(palette().foreground),
+            shadow: iced::Shadow::default(), // 添加 shadow 字段
+        }
+    }
+}
+
+struct ButtonStyle;
+
+impl iced::widget::button::StyleSheet for ButtonStyle {
+    type Style = iced::Theme;
+
+    fn active(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            background: Some(palette.accent.into()),
+            border: iced::Border {
+                radius: 5.0.into(),
+                width: 1.0,
+                color: palette.accent,
+            },
+            text_color: palette.on_accent,
+            ..Default::default()
+        }
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            background: Some(palette.highlight.into()),
+            text_color: palette.on_highlight,
+            ..self.active(_style)
+        }
+    }
+
+    fn pressed(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        iced::widget::button::Appearance {
+            border: iced::Border {
+                width: 2.0,
                 ..self.active(_style).border
             },
-            icon_color: Color::from_rgb(0.3, 0.3, 0.3),
+            ..self.active(_style)
+        }
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            background: Some(palette.surface.into()),
+            text_color: palette.muted,
+            ..self.active(_style)
+        }
+    }
+}
+
+struct DestructiveButtonStyle;
+
+impl iced::widget::button::StyleSheet for DestructiveButtonStyle {
+    type Style = iced::Theme;
+
+    fn active(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            background: Some(palette.danger.into()),
+            border: iced::Border {
+                radius: 5.0.into(),
+                width: 1.0,
+                color: palette.danger,
+            },
+            text_color: palette.on_accent,
+            ..Default::default()
+        }
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            border: iced::Border {
+                color: palette.highlight,
+                ..self.active(_style).border
+            },
+            text_color: palette.on_highlight,
+            ..self.active(_style)
+        }
+    }
+
+    fn pressed(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        iced::widget::button::Appearance {
+            border: iced::Border {
+                width: 2.0,
+                ..self.active(_style).border
+            },
+            ..self.active(_style)
+        }
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> iced::widget::button::Appearance {
+        let palette = palette();
+        iced::widget::button::Appearance {
+            background: Some(palette.surface.into()),
+            text_color: palette.muted,
+            ..self.active(_style)
+        }
+    }
+}
+
+struct TextInputStyle;
+
+impl iced::widget::text_input::StyleSheet for TextInputStyle {
+    type Style = iced::Theme;
+
+    fn active(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
+        let palette = palette();
+        iced::widget::text_input::Appearance {
+            background: palette.surface.into(),
+            border: iced::Border {
+                radius: 5.0.into(),
+                width: 1.0,
+                color: palette.accent,
+            },
+            icon_color: palette.foreground,
+        }
+    }
+
+    fn focused(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
+        iced::widget::text_input::Appearance {
+            border: iced::Border {
+                color: palette().highlight,
+                ..self.active(_style).border
+            },
+            ..self.active(_style)
+        }
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
+        self.focused(_style)
+    }
+
+    fn placeholder_color(&self, _style: &Self::Style) -> Color {
+        palette().muted
+    }
+
+    fn value_color(&self, _style: &Self::Style) -> Color {
+        palette().foreground
+    }
+
+    fn disabled_color(&self, _style: &Self::Style) -> Color {
+        palette().muted
+    }
+
+    fn selection_color(&self, _style: &Self::Style) -> Color {
+        palette().accent
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> iced::widget::text_input::Appearance {
+        let palette = palette();
+        iced::widget::text_input::Appearance {
+            border: iced::Border {
+                color: palette.muted,
+                ..self.active(_style).border
+            },
+            icon_color: palette.muted,
+            ..self.active(_style)
         }
     }
 }
 
-struct CyberCheckboxStyle;
+struct CheckboxStyle;
 
-impl iced::widget::checkbox::StyleSheet for CyberCheckboxStyle {
+impl iced::widget::checkbox::StyleSheet for CheckboxStyle {
     type Style = iced::Theme;
 
     fn active(&self, _style: &Self::Style, is_checked: bool) -> iced::widget::checkbox::Appearance {
+        let palette = palette();
         iced::widget::checkbox::Appearance {
             background: if is_checked {
-                CYBER_ACCENT_BLUE.into()
+                palette.accent.into()
             } else {
-                CYBER_GREY.into()
+                palette.surface.into()
             },
             border: iced::Border {
                 radius: 3.0.into(),
                 width: 1.0,
-                color: CYBER_ACCENT_BLUE,
+                color: palette.accent,
             },
-            icon_color: Color::BLACK,
-            text_color: Some(CYBER_FOREGROUND),
+            icon_color: palette.on_accent,
+            text_color: Some(palette.foreground),
         }
     }
 
@@ -549,7 +2289,7 @@ impl iced::widget::checkbox::StyleSheet for CyberCheckboxStyle {
     ) -> iced::widget::checkbox::Appearance {
         iced::widget::checkbox::Appearance {
             border: iced::Border {
-                color: CYBER_ACCENT_PURPLE,
+                color: palette().highlight,
                 ..self.active(_style, is_checked).border
             },
             ..self.active(_style, is_checked)
@@ -0,0 +1,115 @@
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use curve25519_dalek::edwards::EdwardsPoint;
+use sha2::Sha512;
+use std::fs;
+use std::path::Path;
+
+use crate::i18n::t;
+
+type Blake2b256 = Blake2b<U32>;
+
+// Sui 密钥库中 Ed25519 密钥的标志字节
+const ED25519_FLAG: u8 = 0x00;
+
+// Sui 地址是 32 字节的十六进制，带 0x 前缀；统一为小写
+pub fn validate_sui_address(input: &str) -> Result<String, String> {
+    let address = input.trim().to_ascii_lowercase();
+    match address.strip_prefix("0x") {
+        Some(hex) if hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()) => Ok(address),
+        _ => Err(t!("invalid-sui-address", address = input.trim())),
+    }
+}
+
+// 由 32 字节种子计算 Ed25519 公钥（RFC 8032）
+fn ed25519_public_key(seed: &[u8; 32]) -> [u8; 32] {
+    let hash = Sha512::digest(seed);
+    let mut scalar = [0u8; 32];
+    scalar.copy_from_slice(&hash[..32]);
+    EdwardsPoint::mul_base_clamped(scalar).compress().to_bytes()
+}
+
+// Sui 地址 = BLAKE2b-256(标志字节 || 公钥)
+fn sui_address(flag: u8, public_key: &[u8]) -> String {
+    let mut hasher = Blake2b256::new();
+    hasher.update([flag]);
+    hasher.update(public_key);
+    let digest = hasher.finalize();
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("0x{}", hex)
+}
+
+// 密钥库（sui.keystore）是 Base64 字符串的 JSON 数组，每项为 标志字节 || 私钥。
+// 只读取私钥来计算地址，不会保存；目前只支持 Ed25519 密钥，其他类型跳过
+pub fn keystore_addresses(contents: &str) -> Result<Vec<String>, String> {
+    let keys: Vec<String> = serde_json::from_str(contents)
+        .map_err(|e| t!("invalid-keystore", error = e.to_string()))?;
+    let mut addresses = Vec::new();
+    for key in keys {
+        let bytes = STANDARD
+            .decode(key.trim())
+            .map_err(|e| t!("invalid-keystore", error = e.to_string()))?;
+        if let Some((&ED25519_FLAG, seed)) = bytes.split_first()
+            && let Ok(seed) = <[u8; 32]>::try_from(seed)
+        {
+            addresses.push(sui_address(ED25519_FLAG, &ed25519_public_key(&seed)));
+        }
+    }
+    Ok(addresses)
+}
+
+pub fn read_keystore(path: &Path) -> Result<Vec<String>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| t!("read-keystore-failed", error = e.to_string()))?;
+    let addresses = keystore_addresses(&contents)?;
+    if addresses.is_empty() {
+        return Err(t!("keystore-has-no-ed25519-keys"));
+    }
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn keystore_keys_become_sui_addresses() {
+        // RFC 8032 测试向量 1
+        let seed: [u8; 32] =
+            from_hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            ed25519_public_key(&seed).to_vec(),
+            from_hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+        );
+
+        let mut ed25519 = vec![ED25519_FLAG];
+        ed25519.extend_from_slice(&seed);
+        let mut secp256k1 = vec![0x01];
+        secp256k1.extend_from_slice(&seed);
+        let keystore =
+            serde_json::to_string(&[STANDARD.encode(&ed25519), STANDARD.encode(&secp256k1)])
+                .unwrap();
+        let addresses = keystore_addresses(&keystore).unwrap();
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(validate_sui_address(&addresses[0]).unwrap(), addresses[0]);
+        assert!(keystore_addresses("{}").is_err());
+
+        let upper = format!(" 0x{} ", "AB".repeat(32));
+        assert_eq!(
+            validate_sui_address(&upper).unwrap(),
+            format!("0x{}", "ab".repeat(32))
+        );
+        assert!(validate_sui_address(&"ab".repeat(32)).is_err());
+        assert!(validate_sui_address("0x1234").is_err());
+    }
+}
@@ -1,9 +1,13 @@
 use std::{fs, path::PathBuf};
 
 use walrus_rs::WalrusClient;
+use walrus_rs::models::BlobStoreResult;
 
-const AGG_URL: &str = "https://aggregator.testnet.walrus.atalma.io";
-const PUB_URL: &str = "https://publisher.walrus-01.tududes.com";
+use crate::blob_envelope::{self, BlobMetadata};
+use crate::download::{ConflictPolicy, DownloadOutcome, sanitize_file_name, save_download};
+use crate::file_crypto::{decrypt_if_needed, encrypt_blob};
+use crate::i18n::t;
+use crate::settings::NetworkProfile;
 
 pub struct WalrusApi {
     client: WalrusClient,
@@ -11,60 +15,266 @@ pub struct WalrusApi {
 
 impl Default for WalrusApi {
     fn default() -> Self {
-        Self {
-            client: WalrusClient::new(AGG_URL, PUB_URL).unwrap(),
-        }
+        Self::new(&NetworkProfile::default())
+    }
+}
+
+// Walrus blob ID 是 32 字节的 URL 安全 base64（无填充）
+const BLOB_ID_LEN: usize = 43;
+
+// 通过聚合器探测到的 blob 信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobProbe {
+    pub size: Option<u64>,
+    pub mime: Option<String>,
+}
+
+pub fn validate_blob_id(id: &str) -> Result<String, String> {
+    let id = id.trim();
+    if id.len() != BLOB_ID_LEN
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(t!("invalid-blob-id", id = id));
     }
+    Ok(id.to_string())
+}
+
+// 上传参数：wrap_metadata 把文件名、类型等元数据和内容一起打包，
+// encrypt 再用随机文件密钥加密（元数据也在密文中），deletable 以可删除方式存储，
+// send_object_to 为接收新建 blob 对象的 Sui 地址，未设置时对象由发布器持有
+#[derive(Debug, Clone, Default)]
+pub struct UploadOptions {
+    pub epochs: u32,
+    pub wrap_metadata: bool,
+    pub encrypt: bool,
+    pub deletable: bool,
+    pub send_object_to: Option<String>,
+}
+
+// 上传结果；加密上传时 file_key 为解密所需的文件密钥
+#[derive(Debug, Clone)]
+pub struct UploadedBlob {
+    pub id: String,
+    pub metadata: BlobMetadata,
+    pub file_key: Option<String>,
+    pub deletable: bool,           // 新创建的 blob 对象是否可删除
+    pub object_id: Option<String>, // 新创建的 blob 对象 ID，blob 已存在时没有
+    pub cost: u64,                 // 本次上传支付的存储费用（FROST），blob 已存在时为 0
+}
+
+// 新创建的 blob 和已认证的 blob 在返回结果中位置不同
+fn blob_id_from_result(result: &BlobStoreResult) -> Result<String, String> {
+    match (&result.newly_created, &result.already_certified) {
+        (Some(created), _) => Ok(created.blob_object.blob_id.clone()),
+        (None, Some(certified)) => Ok(certified.blob_id.clone()),
+        (None, None) => Err(t!("missing-blob-id")),
+    }
+}
+
+// 已认证的 blob 没有创建新对象，不能通过本次上传删除
+fn is_deletable(result: &BlobStoreResult) -> bool {
+    result
+        .newly_created
+        .as_ref()
+        .is_some_and(|created| created.blob_object.deletable)
+}
+
+fn object_id_from_result(result: &BlobStoreResult) -> Option<String> {
+    result
+        .newly_created
+        .as_ref()
+        .map(|created| created.blob_object.id.clone())
 }
 
 impl WalrusApi {
-    pub async fn upload_file(&self, file_path: PathBuf) -> Result<String, String> {
+    // 使用工作区的网络配置创建客户端，地址无效时回退到默认网络
+    pub fn new(profile: &NetworkProfile) -> Self {
+        match WalrusClient::new(&profile.aggregator_url, &profile.publisher_url) {
+            Ok(client) => Self { client },
+            Err(e) => {
+                eprintln!("网络配置无效，使用默认网络: {}", e);
+                let defaults = NetworkProfile::default();
+                Self {
+                    client: WalrusClient::new(&defaults.aggregator_url, &defaults.publisher_url)
+                        .unwrap(),
+                }
+            }
+        }
+    }
+
+    // 上传文件并保存 options.epochs 个存储周期；name 为记录中的文件名
+    pub async fn upload_file(
+        &self,
+        file_path: PathBuf,
+        name: &str,
+        options: UploadOptions,
+    ) -> Result<UploadedBlob, String> {
         println!("上传文件路径: {:?}", file_path.display());
         let data = fs::read(&file_path).map_err(|e| e.to_string())?;
+        let metadata = BlobMetadata::describe(name, &data);
+        let data = if options.wrap_metadata {
+            blob_envelope::wrap(&metadata, &data)?
+        } else {
+            data
+        };
+        let (data, file_key) = if options.encrypt {
+            let (data, file_key) = encrypt_blob(&data)?;
+            (data, Some(file_key))
+        } else {
+            (data, None)
+        };
         let result = self
             .client
-            .store_blob(data, Some(1), None, None, None)
+            .store_blob(
+                data,
+                Some(u64::from(options.epochs)),
+                options.deletable.then_some(true),
+                None,
+                options.send_object_to.as_deref(),
+            )
             .await
             .map_err(|e| e.to_string())?;
-        if result.newly_created.is_none() {
-            Ok(result.already_certified.unwrap().blob_id)
-        } else {
-            Ok(result.newly_created.unwrap().blob_object.blob_id)
-        }
+        Ok(UploadedBlob {
+            id: blob_id_from_result(&result)?,
+            metadata,
+            file_key,
+            deletable: is_deletable(&result),
+            object_id: object_id_from_result(&result),
+            cost: result
+                .newly_created
+                .as_ref()
+                .map_or(0, |created| created.cost),
+        })
     }
 
+    // 下载文件，有文件密钥时先解密；目标已存在时按 policy 处理；未指定文件名时使用元数据中的文件名或根据内容猜测扩展名
     pub async fn download_file(
         &self,
         blob_id: String,
-        file_name: String,
+        file_name: Option<String>,
         download_dir: PathBuf,
-    ) -> Result<String, String> {
+        policy: ConflictPolicy,
+        file_key: Option<String>,
+    ) -> Result<DownloadOutcome, String> {
         let data = self
             .client
             .read_blob_by_id(&blob_id)
             .await
             .map_err(|e| e.to_string())?;
-        fs::create_dir_all(&download_dir).map_err(|e| format!("无法创建下载目录: {}", e))?;
-        let download_path = download_dir.join(&file_name);
-        fs::write(download_path, data).map_err(|e| e.to_string())?;
-        Ok("Ok".to_string())
+        let data = decrypt_if_needed(data, file_key.as_deref())?;
+        let (metadata, content) = blob_envelope::unwrap(data)?;
+        let file_name = file_name
+            .or_else(|| metadata.map(|metadata| metadata.name))
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| blob_envelope::fallback_file_name(&blob_id, &content));
+        let file_name = sanitize_file_name(&file_name)?;
+        fs::create_dir_all(&download_dir)
+            .map_err(|e| t!("create-download-dir-failed", error = e.to_string()))?;
+        save_download(download_dir.join(&file_name), content, policy)
+    }
+
+    // 读取 blob 开头最多 limit 字节，返回的布尔值表示内容是否被截断
+    pub async fn fetch_blob_prefix(
+        &self,
+        blob_id: &str,
+        limit: usize,
+    ) -> Result<(Vec<u8>, bool), String> {
+        let url = self
+            .client
+            .aggregator_url()
+            .join(&format!("v1/blobs/{}", blob_id))
+            .map_err(|e| e.to_string())?;
+        let mut response = self
+            .client
+            .http_client()
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| t!("aggregator-unreachable", error = e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(t!(
+                "aggregator-error-status",
+                status = response.status().to_string()
+            ));
+        }
+        let mut data = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+            let remaining = limit - data.len();
+            if chunk.len() > remaining {
+                data.extend_from_slice(&chunk[..remaining]);
+                return Ok((data, true));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        Ok((data, false))
+    }
+
+    // 生成可以直接在浏览器中下载的聚合器链接，file_name 作为文件名提示附加在查询参数中
+    pub fn share_url(&self, blob_id: &str, file_name: Option<&str>) -> Result<String, String> {
+        // ID 可能来自导入的配置，校验后才拼进路径，避免 ../ 之类的内容改变链接
+        let blob_id = validate_blob_id(blob_id)?;
+        let mut url = self
+            .client
+            .aggregator_url()
+            .join(&format!("v1/blobs/{}", blob_id))
+            .map_err(|e| e.to_string())?;
+        if let Some(file_name) = file_name {
+            url.query_pairs_mut().append_pair("filename", file_name);
+        }
+        Ok(url.to_string())
+    }
+
+    // 只请求响应头，确认 blob 在聚合器上可读并获取大小和类型，不下载内容
+    pub async fn probe_blob(&self, blob_id: &str) -> Result<BlobProbe, String> {
+        let url = self
+            .client
+            .aggregator_url()
+            .join(&format!("v1/blobs/{}", blob_id))
+            .map_err(|e| e.to_string())?;
+        let response = self
+            .client
+            .http_client()
+            .head(url)
+            .send()
+            .await
+            .map_err(|e| t!("aggregator-unreachable", error = e.to_string()))?;
+        let status = response.status();
+        if status.as_u16() == 404 {
+            return Err(t!("blob-not-found", id = blob_id));
+        }
+        if !status.is_success() {
+            return Err(t!("aggregator-error-status", status = status.to_string()));
+        }
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        Ok(BlobProbe {
+            size: header("content-length").and_then(|len| len.parse().ok()),
+            mime: header("content-type").filter(|mime| mime != "application/octet-stream"),
+        })
     }
 }
 
 impl WalrusApi {
-    pub async fn upload_config_data(&self, config_data: String) -> Result<String, String> {
-        println!("上传配置数据。");
+    pub async fn upload_config_data(
+        &self,
+        config_data: String,
+        epochs: u32,
+    ) -> Result<String, String> {
+        println!("上传配置数据，存储周期: {}", epochs);
         let data = config_data.into_bytes();
         let result = self
             .client
-            .store_blob(data, Some(1), None, None, None)
+            .store_blob(data, Some(u64::from(epochs)), None, None, None)
             .await
             .map_err(|e| e.to_string())?;
-        if result.newly_created.is_none() {
-            Ok(result.already_certified.unwrap().blob_id)
-        } else {
-            Ok(result.newly_created.unwrap().blob_object.blob_id)
-        }
+        blob_id_from_result(&result)
     }
 
     pub async fn download_config_by_id(&self, blob_id: String) -> Result<String, String> {
@@ -74,7 +284,7 @@ impl WalrusApi {
             .read_blob_by_id(&blob_id)
             .await
             .map_err(|e| e.to_string())?;
-        String::from_utf8(data).map_err(|e| format!("无法将配置数据解码为 UTF-8: {}", e))
+        String::from_utf8(data).map_err(|e| t!("config-not-utf8", error = e.to_string()))
     }
 }
 
@@ -83,11 +293,37 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn blob_ids_are_validated() {
+        let id = "Gt72sjsONf_6ySL1Mzrxbjl5_WgEWDRjTWhxN8fBeus";
+        assert_eq!(validate_blob_id(&format!(" {} ", id)).unwrap(), id);
+        assert!(validate_blob_id("").is_err());
+        assert!(validate_blob_id(&id[1..]).is_err());
+        assert!(validate_blob_id(&id.replace('G', "/")).is_err());
+
+        let api = WalrusApi::default();
+        assert!(api.share_url("../v1/upload", None).is_err());
+        assert!(
+            api.share_url(id, Some("a b.txt"))
+                .unwrap()
+                .ends_with(&format!("/v1/blobs/{}?filename=a+b.txt", id))
+        );
+    }
+
     #[tokio::test]
     async fn test_upload() {
         let pb = PathBuf::from_str("E:\\dev\\walrus_store\\Cargo.toml").unwrap();
         let walrus_api = WalrusApi::default();
-        let result = walrus_api.upload_file(pb).await;
+        let result = walrus_api
+            .upload_file(
+                pb,
+                "Cargo.toml",
+                UploadOptions {
+                    epochs: 1,
+                    ..Default::default()
+                },
+            )
+            .await;
         println!("result: {:?}", result);
         assert!(result.is_ok());
     }
@@ -100,8 +336,10 @@ mod tests {
         let result = walrus_api
             .download_file(
                 "Gt72sjsONf_6ySL1Mzrxbjl5_WgEWDRjTWhxN8fBeus".to_string(),
-                file_name,
+                Some(file_name),
                 download_dir,
+                ConflictPolicy::Overwrite,
+                None,
             )
             .await;
         println!("result: {:?}", result);
@@ -0,0 +1,147 @@
+use crate::data::FileEntry;
+use crate::file_management::{
+    get_data_dir, get_workspace_dir, get_workspaces_dir, load_file_entries, load_workspace_index,
+    save_file_entries, save_workspace_index,
+};
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+
+pub const DEFAULT_WORKSPACE: &str = "default";
+const MAX_WORKSPACE_NAME_LEN: usize = 64;
+
+// 数据目录中的 workspaces.json，记录当前使用的工作区
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceIndex {
+    pub active: String,
+}
+
+// 在工作区之间复制或移动记录
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    Copy,
+    Move,
+}
+
+// 工作区名称直接用作目录名，不允许路径分隔符等特殊字符
+pub fn validate_workspace_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(t!("empty-workspace-name"));
+    }
+    if name.chars().count() > MAX_WORKSPACE_NAME_LEN {
+        return Err(t!("workspace-name-too-long", max = MAX_WORKSPACE_NAME_LEN));
+    }
+    if name == "." || name == ".." || name.starts_with('.') {
+        return Err(t!("workspace-name-starts-with-dot"));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| c.is_control() || r#"/\:*?"<>|"#.contains(*c))
+    {
+        return Err(t!("workspace-name-invalid-char", char = format!("{:?}", c)));
+    }
+    Ok(name.to_string())
+}
+
+pub fn list_workspaces() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(get_workspaces_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+// 旧版本把文件列表等数据直接放在数据目录中，首次启动时移动到默认工作区
+fn migrate_legacy_data() {
+    if get_workspaces_dir().exists() {
+        return;
+    }
+    let data_dir = get_data_dir();
+    let workspace_dir = get_workspace_dir(DEFAULT_WORKSPACE);
+    for file_name in [
+        "files.json",
+        "settings.json",
+        "config_history.json",
+        "sync_state.json",
+    ] {
+        let legacy_path = data_dir.join(file_name);
+        if legacy_path.exists()
+            && let Err(e) = fs::rename(&legacy_path, workspace_dir.join(file_name))
+        {
+            eprintln!("迁移 {} 到默认工作区失败: {}", file_name, e);
+        }
+    }
+}
+
+// 返回当前使用的工作区，必要时迁移旧数据并创建默认工作区
+pub fn load_active_workspace() -> String {
+    migrate_legacy_data();
+    let index = load_workspace_index();
+    let active = if validate_workspace_name(&index.active).is_ok() {
+        index.active
+    } else {
+        DEFAULT_WORKSPACE.to_string()
+    };
+    get_workspace_dir(&active);
+    active
+}
+
+pub fn save_active_workspace(workspace: &str) {
+    save_workspace_index(&WorkspaceIndex {
+        active: workspace.to_string(),
+    });
+}
+
+pub fn create_workspace(name: &str) -> Result<String, String> {
+    let name = validate_workspace_name(name)?;
+    if get_workspaces_dir().join(&name).exists() {
+        return Err(t!("workspace-exists", name = name));
+    }
+    get_workspace_dir(&name);
+    Ok(name)
+}
+
+pub fn delete_workspace(name: &str) -> Result<(), String> {
+    let name = validate_workspace_name(name)?;
+    fs::remove_dir_all(get_workspaces_dir().join(&name))
+        .map_err(|e| t!("delete-workspace-failed", error = e.to_string()))
+}
+
+// 把记录复制到目标工作区，目标中已有相同 ID 的记录不会被覆盖；返回新增的数量
+pub fn copy_entries_to_workspace(entries: &[FileEntry], target: &str) -> usize {
+    let mut target_files = load_file_entries(target);
+    let mut existing: HashSet<String> = target_files.iter().map(|f| f.id.clone()).collect();
+    let mut added = 0;
+    for entry in entries {
+        if existing.insert(entry.id.clone()) {
+            target_files.push(entry.clone());
+            added += 1;
+        }
+    }
+    save_file_entries(target, &target_files);
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workspace_names_are_validated() {
+        assert_eq!(validate_workspace_name(" 工作 ").unwrap(), "工作");
+        assert!(validate_workspace_name("").is_err());
+        assert!(validate_workspace_name("..").is_err());
+        assert!(validate_workspace_name("a/b").is_err());
+        assert!(validate_workspace_name("a\\b").is_err());
+        assert!(validate_workspace_name(&"x".repeat(65)).is_err());
+    }
+}